@@ -0,0 +1,48 @@
+/// Appends `Argument[1]` to the end of the file at `Argument[0]`.
+///
+/// The file is opened in append mode and created if it does not exist yet, so
+/// existing content is never rewritten.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Content =
+		Argument[1].as_str().ok_or(Error::Execution("Invalid content".to_string()))?.as_bytes();
+
+	let mut File = OpenOptions::new()
+		.append(true)
+		.create(true)
+		.open(Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?)
+		.await?;
+
+	File.write_all(Content).await?;
+
+	// Waits for the write to land, which a dropped tokio file does not
+	File.flush().await?;
+
+	Ok(json!(format!("Appended {} bytes", Content.len())))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::{fs::OpenOptions, io::AsyncWriteExt};
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn CreatesThenKeepsContent() {
+		let Root = Fixture::Fn("Append");
+
+		let Path = Root.join("File.txt");
+
+		for Content in ["First", "Second"] {
+			Fn(vec![json!(Path.to_str().unwrap()), json!(Content)]).await.unwrap();
+		}
+
+		assert_eq!(std::fs::read_to_string(&Path).unwrap(), "FirstSecond");
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -0,0 +1,94 @@
+/// Appends `Argument[1]`, serialized as compact JSON, as one line to the file
+/// at `Argument[0]`.
+///
+/// The line and its trailing newline go out in a single write to a file
+/// opened in append mode, which places every write at the current end of the
+/// file. Concurrent appends thus do not interleave within a line on local
+/// filesystems, as long as the line fits in one write (a pipe only guarantees
+/// this up to `PIPE_BUF`, 4096 bytes on Linux). Network filesystems may not
+/// honor append mode atomically.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let mut Line = serde_json::to_vec(&Argument[1])
+		.map_err(|_Error| Error::Execution(format!("Invalid value: {}", _Error)))?;
+
+	Line.push(b'\n');
+
+	let mut File = OpenOptions::new()
+		.append(true)
+		.create(true)
+		.open(Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?)
+		.await?;
+
+	File.write_all(&Line).await?;
+
+	File.flush().await?;
+
+	Ok(json!(format!("Appended {} bytes", Line.len())))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::{fs::OpenOptions, io::AsyncWriteExt};
+
+#[cfg(test)]
+mod Test {
+	use serde_json::{Value, json};
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn AppendsOneLinePerValue() {
+		let Root = Fixture::Fn("AppendLine");
+
+		let Path = Root.join("Log.jsonl");
+
+		let Line = [json!({ "Event": "Start" }), json!("Multi\nline"), json!([1, 2])];
+
+		for Value in &Line {
+			Fn(vec![json!(Path.to_str().unwrap()), Value.clone()]).await.unwrap();
+		}
+
+		let Content = std::fs::read_to_string(&Path).unwrap();
+
+		assert_eq!(
+			Content.lines().map(|Line| serde_json::from_str(Line).unwrap()).collect::<Vec<Value>>(),
+			Line
+		);
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+
+	#[tokio::test]
+	async fn KeepsConcurrentLinesWhole() {
+		let Root = Fixture::Fn("AppendLineConcurrent");
+
+		let Path = Root.join("Log.jsonl");
+
+		let Task = (0..32)
+			.map(|Index| {
+				let Path = Path.clone();
+
+				tokio::spawn(async move {
+					Fn(vec![json!(Path.to_str().unwrap()), json!({ "Index": Index })]).await
+				})
+			})
+			.collect::<Vec<_>>();
+
+		for Task in Task {
+			Task.await.unwrap().unwrap();
+		}
+
+		let mut Index = std::fs::read_to_string(&Path)
+			.unwrap()
+			.lines()
+			.map(|Line| serde_json::from_str::<Value>(Line).unwrap()["Index"].as_u64().unwrap())
+			.collect::<Vec<_>>();
+
+		Index.sort();
+
+		assert_eq!(Index, (0..32).collect::<Vec<_>>());
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
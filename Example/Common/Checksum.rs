@@ -0,0 +1,83 @@
+/// Computes the digest of the file at `Argument[0]` with the algorithm named
+/// in `Argument[1]`.
+///
+/// Only `SHA-256` is supported. The file is hashed in 64 KiB chunks, so memory
+/// use stays flat for large files. The result is the lowercase hex digest.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Algorithm =
+		Argument[1].as_str().ok_or(Error::Execution("Invalid algorithm".to_string()))?;
+
+	if !Algorithm.eq_ignore_ascii_case("SHA-256") && !Algorithm.eq_ignore_ascii_case("SHA256") {
+		return Err(Error::Execution(format!("Unsupported algorithm: {}", Algorithm)));
+	}
+
+	let mut File =
+		File::open(Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?)
+			.await?;
+
+	let mut Hasher = Sha256::new();
+
+	let mut Chunk = vec![0u8; 64 * 1024];
+
+	loop {
+		let Read = File.read(&mut Chunk).await?;
+
+		if Read == 0 {
+			break;
+		}
+
+		Hasher.update(&Chunk[..Read]);
+	}
+
+	Ok(json!(
+		Hasher.finalize().iter().map(|Byte| format!("{:02x}", Byte)).collect::<String>()
+	))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use tokio::{fs::File, io::AsyncReadExt};
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn HashesKnownDigest() {
+		let Root = Fixture::Fn("Checksum");
+
+		for (Name, Content, Digest) in [
+			(
+				"Fox.txt",
+				b"The quick brown fox jumps over the lazy dog".to_vec(),
+				"d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592",
+			),
+			(
+				"Large.txt",
+				vec![b'a'; 100_000],
+				"6d1cf22d7cc09b085dfc25ee1a1f3ae0265804c607bc2074ad253bcc82fd81ee",
+			),
+		] {
+			std::fs::write(Root.join(Name), Content).unwrap();
+
+			assert_eq!(
+				Fn(vec![json!(Root.join(Name).to_str().unwrap()), json!("SHA-256")])
+					.await
+					.unwrap(),
+				json!(Digest)
+			);
+		}
+
+		for (Name, Algorithm) in [("Fox.txt", "MD5"), ("Missing", "SHA-256")] {
+			assert!(
+				Fn(vec![json!(Root.join(Name).to_str().unwrap()), json!(Algorithm)]).await.is_err()
+			);
+		}
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
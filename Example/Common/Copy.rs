@@ -0,0 +1,50 @@
+/// Copies the file at `Argument[0]` to `Argument[1]` byte for byte.
+///
+/// Unlike a `Read` followed by a `Write`, this preserves binary content. The
+/// result reports the number of bytes copied.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Byte = copy(
+		Argument[0].as_str().ok_or(Error::Execution("Invalid source path".to_string()))?,
+		Argument[1].as_str().ok_or(Error::Execution("Invalid destination path".to_string()))?,
+	)
+	.await?;
+
+	Ok(json!(format!("Copied {} bytes", Byte)))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::fs::copy;
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn CopiesBinaryExactly() {
+		let Root = Fixture::Fn("Copy");
+
+		let Content:Vec<u8> = (0..=255).collect();
+
+		std::fs::write(Root.join("From"), &Content).unwrap();
+
+		assert_eq!(
+			Fn(vec![
+				json!(Root.join("From").to_str().unwrap()),
+				json!(Root.join("To").to_str().unwrap()),
+			])
+			.await
+			.unwrap(),
+			json!("Copied 256 bytes")
+		);
+
+		assert_eq!(std::fs::read(Root.join("To")).unwrap(), Content);
+
+		assert_eq!(std::fs::read(Root.join("From")).unwrap(), Content);
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -0,0 +1,64 @@
+/// Creates the directory at `Argument[0]`, along with any missing parents
+/// when `Argument[1]` is `true`.
+///
+/// An existing directory is left as is and reported as success. An existing
+/// file at the path is an error.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Path = Argument[0].as_str().ok_or(Error::Execution("Invalid directory path".to_string()))?;
+
+	let Recursive =
+		Argument[1].as_bool().ok_or(Error::Execution("Invalid recursive flag".to_string()))?;
+
+	let Result = if Recursive { create_dir_all(Path).await } else { create_dir(Path).await };
+
+	match Result {
+		Ok(_) => {},
+		Err(_Error) if _Error.kind() == ErrorKind::AlreadyExists => {
+			if !metadata(Path).await?.is_dir() {
+				return Err(Error::Execution(format!("{} exists and is not a directory", Path)));
+			}
+		},
+		Err(_Error) => return Err(_Error.into()),
+	}
+
+	Ok(json!(format!("Created {}", Path)))
+}
+
+use std::io::ErrorKind;
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::fs::{create_dir, create_dir_all, metadata};
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn CreatesRecursivelyAndTwice() {
+		let Root = Fixture::Fn("CreateDir");
+
+		let Nested = Root.join("First").join("Second");
+
+		let Argument = |Recursive:bool| vec![json!(Nested.to_str().unwrap()), json!(Recursive)];
+
+		assert!(Fn(Argument(false)).await.is_err());
+
+		Fn(Argument(true)).await.unwrap();
+
+		assert!(Nested.is_dir());
+
+		Fn(Argument(true)).await.unwrap();
+
+		Fn(Argument(false)).await.unwrap();
+
+		std::fs::write(Root.join("File"), "Text").unwrap();
+
+		assert!(Fn(vec![json!(Root.join("File").to_str().unwrap()), json!(true)]).await.is_err());
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -0,0 +1,45 @@
+/// Deletes the file at `Argument[0]`.
+///
+/// The file is removed for good; `Trash` keeps it instead. A missing file
+/// fails the action with a `NotFound` error.
+///
+/// The result is a message naming the deleted file.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Path = Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?;
+
+	remove_file(Path).await?;
+
+	Ok(json!(format!("Deleted {}", Path)))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::fs::remove_file;
+
+#[cfg(test)]
+mod Test {
+	use Echo::Enum::Sequence::Action::Error::Enum as Error;
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn DeletesOnce() {
+		let Root = Fixture::Fn("Delete");
+
+		let Path = Root.join("File.txt");
+
+		std::fs::write(&Path, "Text").unwrap();
+
+		let Argument = || vec![json!(Path.to_str().unwrap())];
+
+		Fn(Argument()).await.unwrap();
+
+		assert!(!Path.exists());
+
+		assert!(matches!(Fn(Argument()).await, Err(Error::NotFound(_))));
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
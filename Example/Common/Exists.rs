@@ -0,0 +1,39 @@
+/// Checks whether anything exists at the path in `Argument[0]`.
+///
+/// A missing path is a successful `false`, not an error. Errors are reserved
+/// for failures that leave the answer unknown, such as a permission denied on
+/// a parent directory.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	Ok(json!(
+		try_exists(Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?)
+			.await?
+	))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::fs::try_exists;
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn TellsPresentFromMissing() {
+		let Root = Fixture::Fn("Exists");
+
+		std::fs::write(Root.join("File.txt"), "Text").unwrap();
+
+		for (Name, Exists) in [("File.txt", true), ("Missing", false)] {
+			assert_eq!(
+				Fn(vec![json!(Root.join(Name).to_str().unwrap())]).await.unwrap(),
+				json!(Exists)
+			);
+		}
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -0,0 +1,25 @@
+/// Creates an empty directory for a test to work in.
+///
+/// The directory lies in the system temporary directory and is named after
+/// the process and `Name`, so tests running at once never share one. Any
+/// directory left behind by an earlier run is removed first.
+///
+/// # Returns
+///
+/// The path of the directory.
+pub fn Fn(Name:&str) -> PathBuf {
+	let Directory = temp_dir().join(format!("Echo-{}-{}", process::id(), Name));
+
+	let _ = remove_dir_all(&Directory);
+
+	create_dir_all(&Directory).unwrap();
+
+	Directory
+}
+
+use std::{
+	env::temp_dir,
+	fs::{create_dir_all, remove_dir_all},
+	path::PathBuf,
+	process,
+};
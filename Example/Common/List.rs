@@ -0,0 +1,73 @@
+/// Lists the entries of the directory at `Argument[0]`.
+///
+/// The result is a JSON array of `{ "Name", "Directory" }` objects, sorted by
+/// name, where `Directory` tells sub-directories apart from files.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Path = Argument[0].as_str().ok_or(Error::Execution("Invalid directory path".to_string()))?;
+
+	if !metadata(Path).await?.is_dir() {
+		return Err(Error::Execution(format!("Cannot list {}: not a directory", Path)));
+	}
+
+	let mut Directory = read_dir(Path).await?;
+
+	let mut Entry = Vec::new();
+
+	while let Some(Next) = Directory.next_entry().await? {
+		Entry.push((
+			Next.file_name().to_string_lossy().into_owned(),
+			Next.file_type().await?.is_dir(),
+		));
+	}
+
+	Entry.sort();
+
+	Ok(Value::Array(
+		Entry
+			.into_iter()
+			.map(|(Name, Directory)| json!({ "Name": Name, "Directory": Directory }))
+			.collect(),
+	))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::fs::{metadata, read_dir};
+
+#[cfg(test)]
+mod Test {
+	use Echo::Enum::Sequence::Action::Error::Enum as Error;
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn ListsSortedEntries() {
+		let Root = Fixture::Fn("List");
+
+		std::fs::write(Root.join("File.txt"), "Text").unwrap();
+
+		std::fs::create_dir(Root.join("Directory")).unwrap();
+
+		assert_eq!(
+			Fn(vec![json!(Root.to_str().unwrap())]).await.unwrap(),
+			json!([
+				{ "Name": "Directory", "Directory": true },
+				{ "Name": "File.txt", "Directory": false },
+			])
+		);
+
+		assert!(matches!(
+			Fn(vec![json!(Root.join("File.txt").to_str().unwrap())]).await,
+			Err(Error::Execution(_))
+		));
+
+		assert!(matches!(
+			Fn(vec![json!(Root.join("Missing").to_str().unwrap())]).await,
+			Err(Error::NotFound(_))
+		));
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -0,0 +1,66 @@
+/// Moves the file at `Argument[0]` to `Argument[1]`, replacing any existing
+/// destination.
+///
+/// Within one filesystem this is a single `rename`. `rename` cannot cross
+/// filesystems, so in that case the file is copied and the source removed
+/// instead, which is no longer atomic.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let From = Argument[0].as_str().ok_or(Error::Execution("Invalid source path".to_string()))?;
+
+	let To = Argument[1].as_str().ok_or(Error::Execution("Invalid destination path".to_string()))?;
+
+	match rename(From, To).await {
+		Ok(_) => {},
+		Err(_Error) if _Error.kind() == ErrorKind::CrossesDevices => {
+			copy(From, To).await?;
+
+			remove_file(From).await?;
+		},
+		Err(_Error) => return Err(_Error.into()),
+	}
+
+	Ok(json!(format!("Moved {} to {}", From, To)))
+}
+
+use std::io::ErrorKind;
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::fs::{copy, remove_file, rename};
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn RenamesAndOverwrites() {
+		let Root = Fixture::Fn("Move");
+
+		let [From, To] = ["From.txt", "To.txt"].map(|Name| Root.join(Name));
+
+		let Argument = || vec![json!(From.to_str().unwrap()), json!(To.to_str().unwrap())];
+
+		std::fs::write(&From, "First").unwrap();
+
+		Fn(Argument()).await.unwrap();
+
+		assert!(!From.exists());
+
+		assert_eq!(std::fs::read_to_string(&To).unwrap(), "First");
+
+		std::fs::write(&From, "Second").unwrap();
+
+		Fn(Argument()).await.unwrap();
+
+		assert!(!From.exists());
+
+		assert_eq!(std::fs::read_to_string(&To).unwrap(), "Second");
+
+		assert!(Fn(Argument()).await.is_err());
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -1,16 +1,85 @@
-// Define actions for file reading and writing
+/// Reads the file at `Argument[0]` as text.
+///
+/// Content that is not valid UTF-8 is refused with an error giving the offset
+/// of the first invalid byte, rather than being silently altered. If
+/// `Argument[1]` is `true`, such content is converted lossily instead, each
+/// invalid sequence becoming U+FFFD. Binary content is better read with
+/// `ReadRange`, which returns it as base64.
 pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
-	let mut Content = String::new();
+	let Path = Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?;
 
-	File::open(Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?)
-		.await
-		.map_err(|_Error| Error::Execution(_Error.to_string()))?
-		.read_to_string(&mut Content)
-		.await
-		.map_err(|_Error| Error::Execution(_Error.to_string()))?;
+	let Lossy = Argument.get(1).and_then(Value::as_bool).unwrap_or(false);
+
+	let mut Content = Vec::new();
+
+	File::open(Path).await?.read_to_end(&mut Content).await?;
+
+	let Content = match String::from_utf8(Content) {
+		Ok(Content) => Content,
+		Err(_Error) if Lossy => String::from_utf8_lossy(_Error.as_bytes()).into_owned(),
+		Err(_Error) => {
+			return Err(Error::Execution(format!(
+				"{} is not valid UTF-8 text: invalid byte at offset {}",
+				Path,
+				_Error.utf8_error().valid_up_to()
+			)));
+		},
+	};
 
 	Ok(json!(Content))
 }
 
 use Echo::Enum::Sequence::Action::Error::Enum as Error;
 use serde_json::{Value, json};
+use tokio::{fs::File, io::AsyncReadExt};
+
+#[cfg(test)]
+mod Test {
+	use std::path::PathBuf;
+
+	use serde_json::{Value, json};
+
+	use super::{Error, Fn};
+	use crate::Common::Fixture;
+
+	/// Writes `Content` to a file in a new fixture directory, returning the
+	/// directory and the path of the file.
+	fn File(Name:&str, Content:&[u8]) -> (PathBuf, String) {
+		let Root = Fixture::Fn(Name);
+
+		let Path = Root.join("File");
+
+		std::fs::write(&Path, Content).unwrap();
+
+		(Root, Path.to_string_lossy().into_owned())
+	}
+
+	#[tokio::test]
+	async fn ReadsText() {
+		let (Root, Path) = File("ReadsText", "Grüße".as_bytes());
+
+		assert_eq!(Fn(vec![json!(Path)]).await.unwrap(), json!("Grüße"));
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+
+	#[tokio::test]
+	async fn RefusesBinary() {
+		let (Root, Path) = File("RefusesBinary", &[b'A', 0xFF, b'B']);
+
+		assert!(matches!(Fn(vec![json!(Path)]).await, Err(Error::Execution(_))));
+
+		assert!(matches!(Fn(vec![json!(Path), json!(false)]).await, Err(Error::Execution(_))));
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+
+	#[tokio::test]
+	async fn ConvertsBinaryLossily() {
+		let (Root, Path) = File("ConvertsBinaryLossily", &[b'A', 0xFF, b'B']);
+
+		assert_eq!(Fn(vec![json!(Path), Value::Bool(true)]).await.unwrap(), json!("A\u{FFFD}B"));
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -0,0 +1,31 @@
+/// Reads the file at `Argument[0]` as text, decompressing it if needed.
+///
+/// A file starting with the gzip magic number, as written by
+/// `WriteCompressed`, is decompressed. Any other file is read as it is, so
+/// this also reads files written by `Write`.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Content =
+		read(Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?)
+			.await?;
+
+	let Content = if Content.starts_with(&[0x1f, 0x8b]) {
+		let mut Decompressed = String::new();
+
+		GzDecoder::new(Content.as_slice())
+			.read_to_string(&mut Decompressed)
+			.map_err(|_Error| Error::Execution(format!("Invalid gzip content: {}", _Error)))?;
+
+		Decompressed
+	} else {
+		String::from_utf8(Content).map_err(|_Error| Error::Execution(_Error.to_string()))?
+	};
+
+	Ok(json!(Content))
+}
+
+use std::io::Read;
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use flate2::read::GzDecoder;
+use serde_json::{Value, json};
+use tokio::fs::read;
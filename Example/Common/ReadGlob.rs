@@ -0,0 +1,99 @@
+/// Reads every file matching the glob pattern in `Argument[0]`.
+///
+/// The result is a JSON object keyed by path. A file that was read maps to
+/// `{ "Content" }` and a file that could not be read maps to `{ "Error" }`, so
+/// one failure does not hide the other matches. Only an invalid pattern fails
+/// the whole action.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Pattern = Argument[0]
+		.as_str()
+		.ok_or(Error::Execution("Invalid glob pattern".to_string()))?
+		.to_string();
+
+	let Path = spawn_blocking(move || {
+		glob(&Pattern).map(|Path| Path.collect::<Vec<_>>()).map_err(|_Error| _Error.to_string())
+	})
+	.await
+	.map_err(|_Error| Error::Execution(_Error.to_string()))?
+	.map_err(|_Error| Error::Execution(format!("Invalid glob pattern: {}", _Error)))?;
+
+	let mut Entry = Map::new();
+
+	for Path in Path {
+		match Path {
+			Ok(Path) => {
+				if metadata(&Path).await.is_ok_and(|Metadata| Metadata.is_dir()) {
+					continue;
+				}
+
+				Entry.insert(
+					Path.to_string_lossy().into_owned(),
+					match read_to_string(&Path).await {
+						Ok(Content) => json!({ "Content": Content }),
+						Err(_Error) => json!({ "Error": _Error.to_string() }),
+					},
+				);
+			},
+			Err(_Error) => {
+				Entry.insert(
+					_Error.path().to_string_lossy().into_owned(),
+					json!({ "Error": _Error.error().to_string() }),
+				);
+			},
+		}
+	}
+
+	Ok(Value::Object(Entry))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use glob::glob;
+use serde_json::{Map, Value, json};
+use tokio::{
+	fs::{metadata, read_to_string},
+	task::spawn_blocking,
+};
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn ReadsMatchingFiles() {
+		let Root = Fixture::Fn("ReadGlob");
+
+		std::fs::write(Root.join("First.txt"), "First").unwrap();
+
+		std::fs::write(Root.join("Second.txt"), "Second").unwrap();
+
+		std::fs::write(Root.join("Binary.txt"), [0xFF, 0xFE]).unwrap();
+
+		std::fs::write(Root.join("Other.md"), "Other").unwrap();
+
+		std::fs::create_dir(Root.join("Directory.txt")).unwrap();
+
+		let Key = |Name:&str| Root.join(Name).to_string_lossy().into_owned();
+
+		let Read = Fn(vec![json!(Root.join("*.txt").to_str().unwrap())]).await.unwrap();
+
+		assert_eq!(Read.as_object().unwrap().len(), 3);
+
+		assert_eq!(Read[Key("First.txt")], json!({ "Content": "First" }));
+
+		assert_eq!(Read[Key("Second.txt")], json!({ "Content": "Second" }));
+
+		assert!(Read[Key("Binary.txt")]["Error"].is_string());
+
+		assert_eq!(
+			Fn(vec![json!(Root.join("*.none").to_str().unwrap())]).await.unwrap(),
+			json!({})
+		);
+
+		assert!(Fn(vec![json!("[")]).await.is_err());
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
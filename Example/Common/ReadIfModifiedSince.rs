@@ -0,0 +1,74 @@
+/// Reads the file at `Argument[0]` only if it was modified after the time
+/// `Argument[1]`, in seconds since the Unix epoch as `Stat` reports it.
+///
+/// The result is `{ "Modified": true, "Content" }` with the file content, or
+/// `{ "Modified": false }` without reading the file if it has not changed
+/// since. Times are compared in whole seconds, and a file whose modification
+/// time the platform cannot tell is always read.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Path = Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?;
+
+	let Since = Argument[1].as_u64().ok_or(Error::Execution("Invalid time".to_string()))?;
+
+	let Modified = metadata(Path)
+		.await?
+		.modified()
+		.ok()
+		.and_then(|Modified| Modified.duration_since(UNIX_EPOCH).ok())
+		.map(|Modified| Modified.as_secs());
+
+	if Modified.is_some_and(|Modified| Modified <= Since) {
+		return Ok(json!({ "Modified": false }));
+	}
+
+	let Content = read_to_string(Path).await?;
+
+	Ok(json!({ "Modified": true, "Content": Content }))
+}
+
+use std::time::UNIX_EPOCH;
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::fs::{metadata, read_to_string};
+
+#[cfg(test)]
+mod Test {
+	use std::time::UNIX_EPOCH;
+
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn ReadsOnlyWhenNewer() {
+		let Root = Fixture::Fn("ReadIfModifiedSince");
+
+		let Path = Root.join("File.txt");
+
+		std::fs::write(&Path, "Text").unwrap();
+
+		let Modified = std::fs::metadata(&Path)
+			.unwrap()
+			.modified()
+			.unwrap()
+			.duration_since(UNIX_EPOCH)
+			.unwrap()
+			.as_secs();
+
+		assert_eq!(
+			Fn(vec![json!(Path.to_str().unwrap()), json!(Modified - 1)]).await.unwrap(),
+			json!({ "Modified": true, "Content": "Text" })
+		);
+
+		for Since in [Modified, Modified + 60] {
+			assert_eq!(
+				Fn(vec![json!(Path.to_str().unwrap()), json!(Since)]).await.unwrap(),
+				json!({ "Modified": false })
+			);
+		}
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -0,0 +1,76 @@
+/// Reads `Argument[2]` lines starting at line `Argument[1]` (counted from zero)
+/// of the file at `Argument[0]`.
+///
+/// The lines are returned as an array of strings, without their line endings.
+/// The file is read line by line and only up to the requested page, so paging
+/// through a large file does not load it whole. A page that runs past the end
+/// of the file is clamped to it, and a start past the end yields no lines.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Start = Argument[1].as_u64().ok_or(Error::Execution("Invalid start".to_string()))?;
+
+	let Count = Argument[2].as_u64().ok_or(Error::Execution("Invalid count".to_string()))?;
+
+	let File =
+		File::open(Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?)
+			.await?;
+
+	let mut Lines = BufReader::new(File).lines();
+
+	let mut Page = Vec::new();
+
+	let mut Index = 0;
+
+	while Index < Start.saturating_add(Count) {
+		let Some(Line) = Lines.next_line().await? else {
+			break;
+		};
+
+		if Index >= Start {
+			Page.push(Line);
+		}
+
+		Index += 1;
+	}
+
+	Ok(json!(Page))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::{
+	fs::File,
+	io::{AsyncBufReadExt, BufReader},
+};
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn ReadsPages() {
+		let Root = Fixture::Fn("ReadLines");
+
+		let Path = Root.join("File.txt");
+
+		std::fs::write(&Path, "Zero\nOne\r\nTwo\nThree\nFour").unwrap();
+
+		for (Start, Count, Page) in [
+			(0, 2, json!(["Zero", "One"])),
+			(2, 2, json!(["Two", "Three"])),
+			(3, 10, json!(["Three", "Four"])),
+			(10, 2, json!([])),
+		] {
+			assert_eq!(
+				Fn(vec![json!(Path.to_str().unwrap()), json!(Start), json!(Count)])
+					.await
+					.unwrap(),
+				Page
+			);
+		}
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
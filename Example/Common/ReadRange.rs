@@ -0,0 +1,63 @@
+/// Reads `Argument[2]` bytes starting at byte `Argument[1]` of the file at
+/// `Argument[0]`.
+///
+/// The bytes are returned as a standard base64 string, so binary content is
+/// safe. A window that runs past the end of the file is clamped to it, and an
+/// offset past the end yields no bytes.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Offset = Argument[1].as_u64().ok_or(Error::Execution("Invalid offset".to_string()))?;
+
+	let Length = Argument[2].as_u64().ok_or(Error::Execution("Invalid length".to_string()))?;
+
+	let mut File =
+		File::open(Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?)
+			.await?;
+
+	File.seek(SeekFrom::Start(Offset)).await?;
+
+	let mut Content = Vec::new();
+
+	File.take(Length).read_to_end(&mut Content).await?;
+
+	Ok(json!(STANDARD.encode(Content)))
+}
+
+use std::io::SeekFrom;
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use serde_json::{Value, json};
+use tokio::{
+	fs::File,
+	io::{AsyncReadExt, AsyncSeekExt},
+};
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn ReadsWindow() {
+		let Root = Fixture::Fn("ReadRange");
+
+		let Path = Root.join("File");
+
+		std::fs::write(&Path, "0123456789").unwrap();
+
+		for (Offset, Length, Content) in
+			[(3, 4, "MzQ1Ng=="), (8, 10, "ODk="), (20, 4, ""), (0, 0, "")]
+		{
+			assert_eq!(
+				Fn(vec![json!(Path.to_str().unwrap()), json!(Offset), json!(Length)])
+					.await
+					.unwrap(),
+				json!(Content)
+			);
+		}
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
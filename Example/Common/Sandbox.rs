@@ -0,0 +1,196 @@
+/// Resolves `Path` inside the directory `Root`, refusing anything that would
+/// escape it.
+///
+/// Relative paths are taken relative to `Root`. The result is canonicalized,
+/// so `..` components and symbolic links pointing outside of `Root` are
+/// caught as well as absolute paths elsewhere. A path that does not exist yet
+/// (the target of a write) is checked through its parent directory. A
+/// symbolic link whose target does not exist is refused outright, since
+/// writing through it would create the target wherever it points.
+///
+/// # Errors
+///
+/// Returns `PermissionDenied` if the resolved path lies outside `Root` or is a
+/// dangling symbolic link, or the converted input/output error if `Root` or
+/// the parent directory cannot be resolved.
+pub async fn Resolve(Root:&Path, Path:&str) -> Result<PathBuf, Error> {
+	let Root = canonicalize(Root).await?;
+
+	let Joined = Root.join(Path);
+
+	let Resolved = match canonicalize(&Joined).await {
+		Ok(Resolved) => Resolved,
+		Err(_) => {
+			if symlink_metadata(&Joined).await.is_ok() {
+				return Err(Error::PermissionDenied(format!(
+					"{} is a symbolic link to a missing target",
+					Path
+				)));
+			}
+
+			let Name = Joined
+				.file_name()
+				.ok_or(Error::PermissionDenied(format!("{} escapes the sandbox", Path)))?;
+
+			canonicalize(Joined.parent().unwrap_or(&Root)).await?.join(Name)
+		},
+	};
+
+	if !Resolved.starts_with(&Root) {
+		return Err(Error::PermissionDenied(format!("{} escapes the sandbox", Path)));
+	}
+
+	Ok(Resolved)
+}
+
+/// Confines a plan function to the directory `Root`.
+///
+/// Before every call, the arguments at the positions listed in `Path` are
+/// resolved with `Resolve` and replaced by the resolved paths, so the
+/// function only ever sees paths inside `Root`. For instance, `Read` is
+/// confined with `Confine(Root, &[0], Read::Fn)` and `Move` with
+/// `Confine(Root, &[0, 1], Move::Fn)`. Only plain paths can be confined, not
+/// the patterns of `ReadGlob`.
+///
+/// # Arguments
+///
+/// * `Root` - The directory the paths are confined to.
+/// * `Path` - The positions of the path arguments.
+/// * `Function` - The plan function to confine.
+///
+/// # Returns
+///
+/// A plan function refusing paths outside `Root` with `PermissionDenied`.
+pub fn Confine<F, Fut>(Root:PathBuf, Path:&'static [usize], Function:F) -> Type
+where
+	F: Fn(Vec<Value>) -> Fut + Send + Sync + 'static,
+	Fut: Future<Output = Result<Value, Error>> + Send + 'static, {
+	let Root = Arc::new(Root);
+
+	let Function = Arc::new(Function);
+
+	Box::new(move |mut Argument:Vec<Value>| {
+		let Root = Root.clone();
+
+		let Function = Function.clone();
+
+		Box::pin(async move {
+			for &Index in Path {
+				let Given = Argument
+					.get(Index)
+					.and_then(Value::as_str)
+					.ok_or(Error::Execution("Invalid file path".to_string()))?;
+
+				Argument[Index] = json!(Resolve(&Root, Given).await?.to_string_lossy());
+			}
+
+			Function(Argument).await
+		})
+	})
+}
+
+use std::{
+	future::Future,
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Type::Sequence::Action::Function::Type,
+};
+use serde_json::{Value, json};
+use tokio::fs::{canonicalize, symlink_metadata};
+
+#[cfg(test)]
+mod Test {
+	use std::path::PathBuf;
+
+	use Echo::Enum::Sequence::Action::Error::Enum as Error;
+	use serde_json::json;
+
+	use super::{Confine, Resolve};
+	use crate::Common::{Fixture, Read};
+
+	fn Root(Name:&str) -> PathBuf {
+		let Root = Fixture::Fn(Name);
+
+		std::fs::create_dir_all(Root.join("Inside")).unwrap();
+
+		Root
+	}
+
+	#[tokio::test]
+	async fn ResolvesInside() {
+		let Root = Root("SandboxInside");
+
+		let Sandbox = Root.join("Inside");
+
+		std::fs::write(Sandbox.join("File.txt"), "Text").unwrap();
+
+		let Sandbox = Sandbox.canonicalize().unwrap();
+
+		assert_eq!(Resolve(&Sandbox, "File.txt").await.unwrap(), Sandbox.join("File.txt"));
+
+		assert_eq!(Resolve(&Sandbox, "New.txt").await.unwrap(), Sandbox.join("New.txt"));
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+
+	#[tokio::test]
+	async fn RefusesTraversal() {
+		let Root = Root("SandboxTraversal");
+
+		std::fs::write(Root.join("Secret.txt"), "Secret").unwrap();
+
+		let Sandbox = Root.join("Inside");
+
+		for Path in ["../Secret.txt", "../New.txt", "..", "/etc/passwd"] {
+			assert!(matches!(Resolve(&Sandbox, Path).await, Err(Error::PermissionDenied(_))));
+		}
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+
+	#[cfg(unix)]
+	#[tokio::test]
+	async fn RefusesSymlinkEscape() {
+		let Root = Root("SandboxSymlink");
+
+		std::fs::write(Root.join("Secret.txt"), "Secret").unwrap();
+
+		let Sandbox = Root.join("Inside");
+
+		std::os::unix::fs::symlink(Root.join("Secret.txt"), Sandbox.join("Link")).unwrap();
+
+		std::os::unix::fs::symlink(Root.join("Missing.txt"), Sandbox.join("Dangling")).unwrap();
+
+		std::os::unix::fs::symlink(&Root, Sandbox.join("Up")).unwrap();
+
+		for Path in ["Link", "Dangling", "Up/Secret.txt", "Up/New.txt"] {
+			assert!(matches!(Resolve(&Sandbox, Path).await, Err(Error::PermissionDenied(_))));
+		}
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+
+	#[tokio::test]
+	async fn ConfinesFunction() {
+		let Root = Root("SandboxConfine");
+
+		std::fs::write(Root.join("Secret.txt"), "Secret").unwrap();
+
+		std::fs::write(Root.join("Inside").join("File.txt"), "Text").unwrap();
+
+		let Read = Confine(Root.join("Inside"), &[0], Read::Fn);
+
+		assert_eq!(Read(vec![json!("File.txt")]).await.unwrap(), json!("Text"));
+
+		assert!(matches!(
+			Read(vec![json!("../Secret.txt")]).await,
+			Err(Error::PermissionDenied(_))
+		));
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
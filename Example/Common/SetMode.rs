@@ -0,0 +1,57 @@
+/// Sets the Unix permission bits of the file at `Argument[0]` to
+/// `Argument[1]`, for instance `0o755` to make it executable.
+///
+/// Other platforms have no permission bits, so the action reports itself as
+/// unsupported there.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Path = Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?;
+
+	let Mode = Argument[1]
+		.as_u64()
+		.and_then(|Mode| u32::try_from(Mode).ok())
+		.ok_or(Error::Execution("Invalid mode".to_string()))?;
+
+	#[cfg(unix)]
+	{
+		use std::{fs::Permissions, os::unix::fs::PermissionsExt};
+
+		tokio::fs::set_permissions(Path, Permissions::from_mode(Mode)).await?;
+
+		Ok(json!(format!("Set mode of {} to {:o}", Path, Mode)))
+	}
+
+	#[cfg(not(unix))]
+	Err(Error::Execution(format!("Cannot set mode {:o} of {}: unsupported platform", Mode, Path)))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+
+#[cfg(all(test, unix))]
+mod Test {
+	use std::os::unix::fs::PermissionsExt;
+
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn SetsExecutable() {
+		let Root = Fixture::Fn("SetMode");
+
+		let Path = Root.join("Script.sh");
+
+		std::fs::write(&Path, "#!/bin/sh").unwrap();
+
+		for Mode in [0o755, 0o600] {
+			Fn(vec![json!(Path.to_str().unwrap()), json!(Mode)]).await.unwrap();
+
+			assert_eq!(std::fs::metadata(&Path).unwrap().permissions().mode() & 0o777, Mode);
+		}
+
+		assert!(Fn(vec![json!(Path.to_str().unwrap()), json!(-1)]).await.is_err());
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
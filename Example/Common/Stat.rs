@@ -0,0 +1,57 @@
+/// Reports metadata for the path at `Argument[0]` without reading it.
+///
+/// The result is a JSON object with the `Size` in bytes, the `Modified` time
+/// in seconds since the Unix epoch (`null` where the platform cannot tell) and
+/// whether the path is a `Directory`.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Metadata =
+		metadata(Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?)
+			.await?;
+
+	Ok(json!({
+		"Size": Metadata.len(),
+		"Modified": Metadata
+			.modified()
+			.ok()
+			.and_then(|Modified| Modified.duration_since(UNIX_EPOCH).ok())
+			.map(|Modified| Modified.as_secs()),
+		"Directory": Metadata.is_dir(),
+	}))
+}
+
+use std::time::UNIX_EPOCH;
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::fs::metadata;
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn ReportsSize() {
+		let Root = Fixture::Fn("Stat");
+
+		std::fs::write(Root.join("File.txt"), "Twelve bytes").unwrap();
+
+		let Stat = Fn(vec![json!(Root.join("File.txt").to_str().unwrap())]).await.unwrap();
+
+		assert_eq!(Stat["Size"], json!(12));
+
+		assert_eq!(Stat["Directory"], json!(false));
+
+		assert!(Stat["Modified"].is_u64());
+
+		let Stat = Fn(vec![json!(Root.to_str().unwrap())]).await.unwrap();
+
+		assert_eq!(Stat["Directory"], json!(true));
+
+		assert!(Fn(vec![json!(Root.join("Missing").to_str().unwrap())]).await.is_err());
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -0,0 +1,55 @@
+/// Creates a symbolic link at `Argument[1]` pointing to `Argument[0]`.
+///
+/// An existing file or link at `Argument[1]` is an error rather than being
+/// replaced. On Windows the link is a file symlink, which needs Developer
+/// Mode or the symlink privilege; platforms with neither Unix nor Windows
+/// links report the action as unsupported.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Target = Argument[0].as_str().ok_or(Error::Execution("Invalid target path".to_string()))?;
+
+	let Link = Argument[1].as_str().ok_or(Error::Execution("Invalid link path".to_string()))?;
+
+	#[cfg(unix)]
+	tokio::fs::symlink(Target, Link).await?;
+
+	#[cfg(windows)]
+	tokio::fs::symlink_file(Target, Link).await?;
+
+	#[cfg(not(any(unix, windows)))]
+	return Err(Error::Execution("Symbolic links are not supported on this platform".to_string()));
+
+	#[cfg(any(unix, windows))]
+	Ok(json!(format!("Linked {} to {}", Link, Target)))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+
+#[cfg(all(test, unix))]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn LinksWithoutReplacing() {
+		let Root = Fixture::Fn("Symlink");
+
+		let [Target, Link] = ["Target.txt", "Link.txt"].map(|Name| Root.join(Name));
+
+		let Argument = || vec![json!(Target.to_str().unwrap()), json!(Link.to_str().unwrap())];
+
+		std::fs::write(&Target, "Text").unwrap();
+
+		Fn(Argument()).await.unwrap();
+
+		assert_eq!(std::fs::read_link(&Link).unwrap(), Target);
+
+		assert_eq!(std::fs::read_to_string(&Link).unwrap(), "Text");
+
+		assert!(Fn(Argument()).await.is_err());
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
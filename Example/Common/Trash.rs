@@ -0,0 +1,106 @@
+/// Moves the file at `Argument[0]` into the trash directory `Argument[1]`
+/// instead of deleting it.
+///
+/// The trash directory is given with every call, so each plan decides where
+/// its trash lives, and it is created if it does not exist yet. The file
+/// keeps its name there, followed by `.1`, `.2` and so on if a file of that
+/// name is already in the trash. The name is claimed by creating an empty
+/// file under it first, which fails if the name is taken, so two files
+/// trashed at once never overwrite each other or an earlier one. The move
+/// itself is then done as in `Move`, over the claimed file. If the trash
+/// directory cannot be created or written to, the action fails and the file
+/// stays where it was.
+///
+/// The result is the path of the file in the trash.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let From = Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?;
+
+	let Trash =
+		Argument[1].as_str().ok_or(Error::Execution("Invalid trash directory".to_string()))?;
+
+	let Name = Path::new(From)
+		.file_name()
+		.ok_or(Error::Execution(format!("Invalid file path: {}", From)))?
+		.to_string_lossy()
+		.into_owned();
+
+	create_dir_all(Trash)
+		.await
+		.map_err(|_Error| Error::Execution(format!("Trash {} is unavailable: {}", Trash, _Error)))?;
+
+	let mut Counter = 0u64;
+
+	let To = loop {
+		let To = match Counter {
+			0 => Path::new(Trash).join(&Name),
+			_ => Path::new(Trash).join(format!("{}.{}", Name, Counter)),
+		};
+
+		match OpenOptions::new().write(true).create_new(true).open(&To).await {
+			Ok(_) => break To.to_string_lossy().into_owned(),
+			Err(_Error) if _Error.kind() == ErrorKind::AlreadyExists => Counter += 1,
+			Err(_Error) => {
+				return Err(Error::Execution(format!("Trash {} is unavailable: {}", Trash, _Error)));
+			},
+		}
+	};
+
+	if let Err(_Error) = Move::Fn(vec![json!(From), json!(To)]).await {
+		let _ = remove_file(&To).await;
+
+		return Err(_Error);
+	}
+
+	Ok(json!(To))
+}
+
+use std::{io::ErrorKind, path::Path};
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::fs::{OpenOptions, create_dir_all, remove_file};
+
+use super::Move;
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn MovesIntoTrash() {
+		let Root = Fixture::Fn("Trash");
+
+		let Trash = Root.join("Trash");
+
+		let mut Trashed = Vec::new();
+
+		for Content in ["First", "Second", "Third"] {
+			let From = Root.join("File.txt");
+
+			std::fs::write(&From, Content).unwrap();
+
+			let To = Fn(vec![json!(From.to_str().unwrap()), json!(Trash.to_str().unwrap())])
+				.await
+				.unwrap();
+
+			assert!(!From.exists());
+
+			Trashed.push(To.as_str().unwrap().to_string());
+		}
+
+		assert_eq!(
+			Trashed,
+			["File.txt", "File.txt.1", "File.txt.2"]
+				.map(|Name| Trash.join(Name).to_string_lossy().into_owned())
+		);
+
+		for (To, Content) in Trashed.iter().zip(["First", "Second", "Third"]) {
+			assert_eq!(std::fs::read_to_string(To).unwrap(), Content);
+		}
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
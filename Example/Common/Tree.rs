@@ -0,0 +1,144 @@
+/// Lists the directory at `Argument[0]` recursively.
+///
+/// The result is the `{ "Name", "Directory", "Entry" }` object of the root,
+/// where `Entry` holds the same objects for its entries, sorted by name, and
+/// is left out for files. `Argument[1]`, if it is a number, limits how many
+/// levels are expanded; deeper directories come without `Entry`. Symbolic
+/// links are followed, and a directory that is one of its own ancestors is
+/// marked with `"Cycle": true` instead of being expanded again.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Path = Argument[0].as_str().ok_or(Error::Execution("Invalid directory path".to_string()))?;
+
+	let Limit = Argument.get(1).and_then(Value::as_u64);
+
+	if !metadata(Path).await?.is_dir() {
+		return Err(Error::Execution(format!("Cannot list {}: not a directory", Path)));
+	}
+
+	Walk(PathBuf::from(Path), Path.to_string(), 0, Limit, &mut HashSet::new()).await
+}
+
+/// Describes the directory at `Path` and, within the depth `Limit`, its
+/// entries, keeping the canonical paths of its ancestors in `Ancestor`.
+fn Walk<'a>(
+	Path:PathBuf,
+	Name:String,
+	Depth:u64,
+	Limit:Option<u64>,
+	Ancestor:&'a mut HashSet<PathBuf>,
+) -> Pin<Box<dyn Future<Output = Result<Value, Error>> + Send + 'a>> {
+	Box::pin(async move {
+		if Limit.is_some_and(|Limit| Depth >= Limit) {
+			return Ok(json!({ "Name": Name, "Directory": true }));
+		}
+
+		let Canonical = canonicalize(&Path).await?;
+
+		if !Ancestor.insert(Canonical.clone()) {
+			return Ok(json!({ "Name": Name, "Directory": true, "Cycle": true }));
+		}
+
+		let mut Directory = read_dir(&Path).await?;
+
+		let mut Child = Vec::new();
+
+		while let Some(Next) = Directory.next_entry().await? {
+			Child.push((Next.file_name().to_string_lossy().into_owned(), Next.path()));
+		}
+
+		Child.sort();
+
+		let mut Entry = Vec::with_capacity(Child.len());
+
+		for (Name, Path) in Child {
+			// Follows symbolic links, so a link to a directory is expanded
+			let Directory = metadata(&Path).await.is_ok_and(|Metadata| Metadata.is_dir());
+
+			Entry.push(if Directory {
+				Walk(Path, Name, Depth + 1, Limit, Ancestor).await?
+			} else {
+				json!({ "Name": Name, "Directory": false })
+			});
+		}
+
+		Ancestor.remove(&Canonical);
+
+		Ok(json!({ "Name": Name, "Directory": true, "Entry": Entry }))
+	})
+}
+
+use std::{collections::HashSet, future::Future, path::PathBuf, pin::Pin};
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::fs::{canonicalize, metadata, read_dir};
+
+#[cfg(test)]
+mod Test {
+	use Echo::Enum::Sequence::Action::Error::Enum as Error;
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn ListsNestedLevels() {
+		let Root = Fixture::Fn("Tree");
+
+		std::fs::create_dir_all(Root.join("First").join("Second")).unwrap();
+
+		std::fs::write(Root.join("First").join("File.txt"), "Text").unwrap();
+
+		let Path = Root.to_str().unwrap();
+
+		assert_eq!(
+			Fn(vec![json!(Path)]).await.unwrap(),
+			json!({ "Name": Path, "Directory": true, "Entry": [
+				{ "Name": "First", "Directory": true, "Entry": [
+					{ "Name": "File.txt", "Directory": false },
+					{ "Name": "Second", "Directory": true, "Entry": [] },
+				] },
+			] })
+		);
+
+		assert_eq!(
+			Fn(vec![json!(Path), json!(1)]).await.unwrap(),
+			json!({ "Name": Path, "Directory": true, "Entry": [
+				{ "Name": "First", "Directory": true },
+			] })
+		);
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+
+	#[tokio::test]
+	async fn MissingRootIsNotFound() {
+		let Root = Fixture::Fn("TreeMissing");
+
+		assert!(matches!(
+			Fn(vec![json!(Root.join("Missing").to_str().unwrap())]).await,
+			Err(Error::NotFound(_))
+		));
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+
+	#[cfg(unix)]
+	#[tokio::test]
+	async fn MarksCycle() {
+		let Root = Fixture::Fn("TreeCycle");
+
+		std::os::unix::fs::symlink(&Root, Root.join("Loop")).unwrap();
+
+		let Path = Root.to_str().unwrap();
+
+		assert_eq!(
+			Fn(vec![json!(Path)]).await.unwrap(),
+			json!({ "Name": Path, "Directory": true, "Entry": [
+				{ "Name": "Loop", "Directory": true, "Cycle": true },
+			] })
+		);
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -0,0 +1,50 @@
+/// Sets the length of the existing file at `Argument[0]` to `Argument[1]`
+/// bytes.
+///
+/// A shorter length cuts the file, a longer one extends it with zeros and `0`
+/// empties it. The file is never created.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Size = Argument[1].as_u64().ok_or(Error::Execution("Invalid size".to_string()))?;
+
+	OpenOptions::new()
+		.write(true)
+		.open(Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?)
+		.await?
+		.set_len(Size)
+		.await?;
+
+	Ok(json!(format!("Truncated to {} bytes", Size)))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::fs::OpenOptions;
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn CutsExtendsAndEmpties() {
+		let Root = Fixture::Fn("Truncate");
+
+		let Path = Root.join("File.txt");
+
+		std::fs::write(&Path, "Content").unwrap();
+
+		for (Size, Content) in [(3, b"Con".to_vec()), (5, b"Con\0\0".to_vec()), (0, Vec::new())] {
+			Fn(vec![json!(Path.to_str().unwrap()), json!(Size)]).await.unwrap();
+
+			assert_eq!(std::fs::read(&Path).unwrap(), Content);
+		}
+
+		assert!(Fn(vec![json!(Root.join("Missing").to_str().unwrap()), json!(0)]).await.is_err());
+
+		assert!(!Root.join("Missing").exists());
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
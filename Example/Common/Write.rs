@@ -4,19 +4,18 @@ pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
 		.create(true)
 		.truncate(true)
 		.open(Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?)
-		.await
-		.map_err(|_Error| Error::Execution(_Error.to_string()))?
+		.await?
 		.write_all(
 			Argument[1]
 				.as_str()
 				.ok_or(Error::Execution("Invalid content".to_string()))?
 				.as_bytes(),
 		)
-		.await
-		.map_err(|_Error| Error::Execution(_Error.to_string()))?;
+		.await?;
 
 	Ok(json!("File written successfully"))
 }
 
 use Echo::Enum::Sequence::Action::Error::Enum as Error;
 use serde_json::{Value, json};
+use tokio::{fs::OpenOptions, io::AsyncWriteExt};
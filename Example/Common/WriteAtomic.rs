@@ -0,0 +1,94 @@
+/// Writes `Argument[1]` to the file at `Argument[0]` atomically.
+///
+/// The content goes to a temporary file next to the target, which is synced
+/// to disk and then renamed over the target, so readers see either the old
+/// file or the complete new one, never a truncated one. The rename is only
+/// atomic within one filesystem, hence the temporary file in the same
+/// directory. Syncing the file, and on Unix the directory after the rename,
+/// makes the write survive a crash, at the cost of waiting for the disk twice
+/// on every call. On failure the temporary file is removed.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Path = PathBuf::from(
+		Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?,
+	);
+
+	let Content = Argument[1].as_str().ok_or(Error::Execution("Invalid content".to_string()))?;
+
+	let Name = Path
+		.file_name()
+		.ok_or(Error::Execution(format!("Invalid file path: {}", Path.display())))?
+		.to_string_lossy()
+		.into_owned();
+
+	// A random suffix keeps concurrent writes to the same target apart
+	let Temporary = Path.with_file_name(format!(".{}.{:016x}.tmp", Name, rand::random::<u64>()));
+
+	let Result = async {
+		let mut File = File::create(&Temporary).await?;
+
+		File.write_all(Content.as_bytes()).await?;
+
+		File.sync_all().await?;
+
+		rename(&Temporary, &Path).await
+	}
+	.await;
+
+	if let Err(_Error) = Result {
+		let _ = remove_file(&Temporary).await;
+
+		return Err(_Error.into());
+	}
+
+	// Syncs the directory as well, so that the rename itself is durable
+	#[cfg(unix)]
+	File::open(
+		Path.parent()
+			.filter(|Parent| !Parent.as_os_str().is_empty())
+			.unwrap_or(std::path::Path::new(".")),
+	)
+	.await?
+	.sync_all()
+	.await?;
+
+	Ok(json!("File written successfully"))
+}
+
+use std::path::PathBuf;
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use tokio::{
+	fs::{File, remove_file, rename},
+	io::AsyncWriteExt,
+};
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn ReplacesWithoutLeftovers() {
+		let Root = Fixture::Fn("WriteAtomic");
+
+		let Target = Root.join("File.txt");
+
+		std::fs::write(&Target, "Old").unwrap();
+
+		Fn(vec![json!(Target.to_str().unwrap()), json!("New")]).await.unwrap();
+
+		assert_eq!(std::fs::read_to_string(&Target).unwrap(), "New");
+
+		let Entry:Vec<_> = std::fs::read_dir(&Root)
+			.unwrap()
+			.map(|Entry| Entry.unwrap().file_name().to_string_lossy().into_owned())
+			.collect();
+
+		assert_eq!(Entry, ["File.txt"]);
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
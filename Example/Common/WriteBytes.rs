@@ -0,0 +1,57 @@
+/// Writes binary content to the file at `Argument[0]`.
+///
+/// `Argument[1]` carries the bytes as a standard base64 string, which keeps
+/// arbitrary (non-UTF-8) content intact and compact inside JSON arguments.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Content = STANDARD
+		.decode(Argument[1].as_str().ok_or(Error::Execution("Invalid content".to_string()))?)
+		.map_err(|_Error| Error::Execution(format!("Invalid base64 content: {}", _Error)))?;
+
+	write(
+		Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?,
+		&Content,
+	)
+	.await?;
+
+	Ok(json!(format!("Wrote {} bytes", Content.len())))
+}
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use serde_json::{Value, json};
+use tokio::fs::write;
+
+#[cfg(test)]
+mod Test {
+	use Echo::Enum::Sequence::Action::Error::Enum as Error;
+	use base64::{Engine, engine::general_purpose::STANDARD};
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	#[tokio::test]
+	async fn RoundTripsNonUtf8() {
+		let Path = Fixture::Fn("WriteBytes").join("File");
+
+		let Content = [0xFF, 0xFE, 0x00, 0xC3, 0x28, 0x80];
+
+		assert!(String::from_utf8(Content.to_vec()).is_err());
+
+		assert_eq!(
+			Fn(vec![json!(Path.to_str().unwrap()), json!(STANDARD.encode(Content))])
+				.await
+				.unwrap(),
+			json!("Wrote 6 bytes")
+		);
+
+		assert_eq!(std::fs::read(&Path).unwrap(), Content);
+
+		assert!(matches!(
+			Fn(vec![json!(Path.to_str().unwrap()), json!("Not base64!")]).await,
+			Err(Error::Execution(_))
+		));
+
+		std::fs::remove_dir_all(Path.parent().unwrap()).unwrap();
+	}
+}
@@ -0,0 +1,63 @@
+/// Writes `Argument[1]` gzip-compressed to the file at `Argument[0]`.
+///
+/// Gzip output starts with its own magic number, so the file describes its
+/// format and `ReadCompressed` can tell it apart from plain text.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Content = Argument[1].as_str().ok_or(Error::Execution("Invalid content".to_string()))?;
+
+	let mut Encoder = GzEncoder::new(Vec::new(), Compression::default());
+
+	Encoder.write_all(Content.as_bytes())?;
+
+	let Compressed = Encoder.finish()?;
+
+	write(
+		Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?,
+		&Compressed,
+	)
+	.await?;
+
+	Ok(json!(format!("Wrote {} bytes as {} compressed", Content.len(), Compressed.len())))
+}
+
+use std::io::Write;
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use flate2::{Compression, write::GzEncoder};
+use serde_json::{Value, json};
+use tokio::fs::write;
+
+#[cfg(test)]
+mod Test {
+	use serde_json::json;
+
+	use super::Fn;
+	use crate::Common::{Fixture, ReadCompressed};
+
+	#[tokio::test]
+	async fn RoundTripsSmaller() {
+		let Root = Fixture::Fn("WriteCompressed");
+
+		let Path = Root.join("File.gz");
+
+		let Content = "Repetitive content. ".repeat(100);
+
+		Fn(vec![json!(Path.to_str().unwrap()), json!(Content)]).await.unwrap();
+
+		assert!(std::fs::metadata(&Path).unwrap().len() < Content.len() as u64);
+
+		assert_eq!(
+			ReadCompressed::Fn(vec![json!(Path.to_str().unwrap())]).await.unwrap(),
+			json!(Content)
+		);
+
+		std::fs::write(&Path, "Plain").unwrap();
+
+		assert_eq!(
+			ReadCompressed::Fn(vec![json!(Path.to_str().unwrap())]).await.unwrap(),
+			json!("Plain")
+		);
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -0,0 +1,79 @@
+/// Writes `Argument[1]` to the file at `Argument[0]` only if the file still
+/// has the SHA-256 hex digest given in `Argument[2]`.
+///
+/// An empty expected digest means the file must not exist yet. On mismatch
+/// nothing is written and the action fails with a `Conflict` error naming the
+/// current digest, which callers can tell apart from a failed write. The
+/// check and the write are not locked together, so this guards against stale
+/// writers rather than truly simultaneous ones.
+pub async fn Fn(Argument:Vec<Value>) -> Result<Value, Error> {
+	let Path = Argument[0].as_str().ok_or(Error::Execution("Invalid file path".to_string()))?;
+
+	let Content = Argument[1].as_str().ok_or(Error::Execution("Invalid content".to_string()))?;
+
+	let Expected =
+		Argument[2].as_str().ok_or(Error::Execution("Invalid expected digest".to_string()))?;
+
+	let Current = match read(Path).await {
+		Ok(Current) => {
+			Sha256::digest(&Current).iter().map(|Byte| format!("{:02x}", Byte)).collect::<String>()
+		},
+		Err(_Error) if _Error.kind() == ErrorKind::NotFound => String::new(),
+		Err(_Error) => return Err(_Error.into()),
+	};
+
+	if !Current.eq_ignore_ascii_case(Expected) {
+		return Err(Error::Conflict(format!(
+			"Precondition failed for {}: expected {:?}, found {:?}",
+			Path, Expected, Current
+		)));
+	}
+
+	write(Path, Content).await?;
+
+	Ok(json!("File written successfully"))
+}
+
+use std::io::ErrorKind;
+
+use Echo::Enum::Sequence::Action::Error::Enum as Error;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use tokio::fs::{read, write};
+
+#[cfg(test)]
+mod Test {
+	use Echo::Enum::Sequence::Action::Error::Enum as Error;
+	use serde_json::json;
+	use sha2::{Digest, Sha256};
+
+	use super::Fn;
+	use crate::Common::Fixture;
+
+	fn Hash(Content:&str) -> String {
+		Sha256::digest(Content).iter().map(|Byte| format!("{:02x}", Byte)).collect()
+	}
+
+	#[tokio::test]
+	async fn WritesOnlyOverExpectedContent() {
+		let Root = Fixture::Fn("WriteIfMatch");
+
+		let Path = Root.join("File.txt");
+
+		let Argument = |Content:&str, Expected:&str| {
+			vec![json!(Path.to_str().unwrap()), json!(Content), json!(Expected)]
+		};
+
+		Fn(Argument("First", "")).await.unwrap();
+
+		assert!(matches!(Fn(Argument("Again", "")).await, Err(Error::Conflict(_))));
+
+		Fn(Argument("Second", &Hash("First"))).await.unwrap();
+
+		assert!(matches!(Fn(Argument("Stale", &Hash("First"))).await, Err(Error::Conflict(_))));
+
+		assert_eq!(std::fs::read_to_string(&Path).unwrap(), "Second");
+
+		std::fs::remove_dir_all(&Root).unwrap();
+	}
+}
@@ -1,2 +1,29 @@
+pub mod Append;
+pub mod AppendLine;
+pub mod Checksum;
+pub mod Copy;
+pub mod CreateDir;
+pub mod Delete;
+pub mod Exists;
+#[cfg(test)]
+pub mod Fixture;
+pub mod List;
+pub mod Move;
 pub mod Read;
+pub mod ReadCompressed;
+pub mod ReadGlob;
+pub mod ReadIfModifiedSince;
+pub mod ReadLines;
+pub mod ReadRange;
+pub mod Sandbox;
+pub mod SetMode;
+pub mod Stat;
+pub mod Symlink;
+pub mod Trash;
+pub mod Tree;
+pub mod Truncate;
 pub mod Write;
+pub mod WriteAtomic;
+pub mod WriteBytes;
+pub mod WriteCompressed;
+pub mod WriteIfMatch;
@@ -1,28 +1,27 @@
 #![allow(non_snake_case)]
 
-// Define a simple site that implements the Site trait
-struct SimpleSite;
-
-#[async_trait::async_trait]
-impl Site for SimpleSite {
-	async fn Receive(
-		&self,
-		Action:Box<dyn Echo::Trait::Sequence::Action::Trait>,
-		Context:&Life,
-	) -> Result<(), Error> {
-		Action.Execute(Context).await
-	}
-}
-
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	// Confine every file action to the current directory
+	let Root = std::env::current_dir()?;
+
 	// Create a plan with file reading and writing actions
 	let Plan = Arc::new(
 		Echo::Struct::Sequence::Plan::Struct::New()
-			.WithSignature(Signature { Name:"Read".to_string() })
-			.WithSignature(Signature { Name:"Write".to_string() })
-			.WithFunction("Read", Common::Read::Fn)?
-			.WithFunction("Write", Common::Write::Fn)?
+			.WithSignature(
+				Signature::Struct::New("Read")
+					.WithParameter("Path", Kind::String)
+					.WithOptional("Lossy", Kind::Bool)
+					.WithReturn(Kind::String),
+			)
+			.WithSignature(
+				Signature::Struct::New("Write")
+					.WithParameter("Path", Kind::String)
+					.WithParameter("Content", Kind::String)
+					.WithReturn(Kind::String),
+			)
+			.WithFunction("Read", Common::Sandbox::Confine(Root.clone(), &[0], Common::Read::Fn))?
+			.WithFunction("Write", Common::Sandbox::Confine(Root, &[0], Common::Write::Fn))?
 			.Build(),
 	);
 
@@ -37,8 +36,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 		Karma:Arc::new(DashMap::new()),
 	};
 
-	// Create a site
-	let Site = Arc::new(SimpleSite);
+	// Create a site that executes actions directly
+	let Site = Arc::new(Echo::Struct::Sequence::Site::Struct);
 
 	// Create a sequence
 	let Sequence = Echo::Struct::Sequence::Struct::New(Site, Production.clone(), Life);
@@ -46,21 +45,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	// Add actions to the production line
 	// Create actions for reading and writing files
 	Production
-		.Assign(Box::new(Common::New("Read", json!(["input.txt"]), Plan.clone()).clone()))
+		.Assign(Box::new(Action::New("Read", json!(["input.txt"]), Plan.clone())))
 		.await;
 
 	Production
-		.Assign(Box::new(
-			Common::New("Write", json!(["output.txt", "Hello, World!"]), Plan.clone()).clone(),
-		))
+		.Assign(Box::new(Action::New(
+			"Write",
+			json!(["output.txt", "Hello, World!"]),
+			Plan.clone(),
+		)))
 		.await;
 
 	let CloneSequence = Sequence.clone();
 
 	// Run the sequence
 	tokio::spawn(async move {
-		// TODO: thread 'tokio-runtime-worker' has overflowed its stack
-		// CloneSequence.Run().await;
+		CloneSequence.Run().await;
 	});
 
 	// Wait for a moment to allow actions to complete
@@ -75,19 +75,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 use Echo::{
-	Enum::Sequence::Action::Error::Enum as Error,
+	Enum::Sequence::Action::Signature::Kind::Enum as Kind,
 	Struct::Sequence::{
-		Action::{Signature::Struct as Signature, Struct as Action},
+		Action::{Signature, Struct as Action},
 		Arc,
 		Life::Struct as Life,
 	},
-	Trait::Sequence::Site::Trait as Site,
 };
 use dashmap::DashMap;
-use serde_json::{Value, json};
-use tokio::{
-	fs::{File, OpenOptions},
-	io::{AsyncReadExt, AsyncWriteExt},
-};
+use serde_json::json;
 
 pub mod Common;
@@ -17,8 +17,18 @@ impl Site for SimpleSite {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	let Plan = Arc::new(
 		Echo::Struct::Sequence::Plan::Struct::New()
-			.WithSignature(Action::Signature::Struct { Name:"Read".to_string() })
-			.WithSignature(Action::Signature::Struct { Name:"Write".to_string() })
+			.WithSignature(
+				Action::Signature::Struct::New("Read")
+					.WithParameter("Path", Kind::String)
+					.WithOptional("Lossy", Kind::Bool)
+					.WithReturn(Kind::String),
+			)
+			.WithSignature(
+				Action::Signature::Struct::New("Write")
+					.WithParameter("Path", Kind::String)
+					.WithParameter("Content", Kind::String)
+					.WithReturn(Kind::String),
+			)
 			.WithFunction("Read", Common::Read::Fn)?
 			.WithFunction("Write", Common::Write::Fn)?
 			.Build(),
@@ -124,7 +134,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 use std::sync::Arc;
 
 use Echo::{
-	Enum::Sequence::Action::Error::Enum as Error,
+	Enum::Sequence::Action::{Error::Enum as Error, Signature::Kind::Enum as Kind},
 	Struct::Sequence::{self, Action, Life::Struct as Life, Plan},
 	Trait::Sequence::Site,
 };
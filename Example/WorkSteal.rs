@@ -79,8 +79,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	// Create a plan with file reading and writing actions
 	let Plan = Arc::new(
 		Echo::Struct::Sequence::Plan::Struct::New()
-			.WithSignature(Signature { Name:"Read".to_string() })
-			.WithSignature(Signature { Name:"Write".to_string() })
+			.WithSignature(
+				Signature::Struct::New("Read")
+					.WithParameter("Path", Kind::String)
+					.WithOptional("Lossy", Kind::Bool)
+					.WithReturn(Kind::String),
+			)
+			.WithSignature(
+				Signature::Struct::New("Write")
+					.WithParameter("Path", Kind::String)
+					.WithParameter("Content", Kind::String)
+					.WithReturn(Kind::String),
+			)
 			.WithFunction("Read", Common::Read::Fn)?
 			.WithFunction("Write", Common::Write::Fn)?
 			.Build(),
@@ -154,9 +164,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 use std::sync::Arc;
 
 use Echo::{
-	Enum::Sequence::Action::Error::Enum as Error,
+	Enum::Sequence::Action::{Error::Enum as Error, Signature::Kind::Enum as Kind},
 	Struct::Sequence::{
-		Action::{Signature::Struct as Signature, Struct as Action},
+		Action::{Signature, Struct as Action},
 		Arc,
 		Life::Struct as Life,
 	},
@@ -1,5 +1,8 @@
 /// Represents various error types that can occur during sequence actions.
-#[derive(Debug, Error)]
+///
+/// Errors can be serialized, so they can be sent across processes together
+/// with the action that produced them.
+#[derive(Clone, Debug, Error, Serialize, Deserialize)]
 pub enum Enum {
 	/// Indicates an error related to an invalid license.
 	///
@@ -32,6 +35,116 @@ pub enum Enum {
 	/// * `String` - A description of the specific cancellation error.
 	#[error("Cancellation error: {0}")]
 	Cancellation(String),
+
+	/// Signifies that an action did not complete within its allotted time.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the specific timeout.
+	#[error("Timeout error: {0}")]
+	Timeout(String),
+
+	/// Signifies that an action reached its own deadline, before or while it
+	/// was executed.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the missed deadline.
+	#[error("Expired: {0}")]
+	Expired(String),
+
+	/// Indicates that the arguments of a call do not match the action
+	/// signature.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the specific argument mismatch.
+	#[error("Argument error: {0}")]
+	Argument(String),
+
+	/// Indicates that a resource needed by an action does not exist.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the missing resource.
+	#[error("Not found: {0}")]
+	NotFound(String),
+
+	/// Indicates that an action was not allowed to access a resource.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the refused access.
+	#[error("Permission denied: {0}")]
+	PermissionDenied(String),
+
+	/// Indicates that a resource is not in the state an action required,
+	/// such as a file changed since it was last read.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the failed precondition.
+	#[error("Conflict: {0}")]
+	Conflict(String),
+
+	/// Represents any other input/output failure.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the specific input/output error.
+	#[error("IO error: {0}")]
+	Io(String),
+}
+
+/// Converts an input/output error, so plan functions and sites can use `?`
+/// on filesystem calls.
+///
+/// Missing resources become `NotFound`, refused access becomes
+/// `PermissionDenied`, and every other failure becomes `Io`.
+impl From<std::io::Error> for Enum {
+	fn from(_Error:std::io::Error) -> Self {
+		match _Error.kind() {
+			ErrorKind::NotFound => Enum::NotFound(_Error.to_string()),
+			ErrorKind::PermissionDenied => Enum::PermissionDenied(_Error.to_string()),
+			_ => Enum::Io(_Error.to_string()),
+		}
+	}
 }
 
+use std::io::ErrorKind;
+
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+
+#[cfg(test)]
+mod Test {
+	use std::io::ErrorKind;
+
+	use super::Enum;
+
+	fn Fail(Kind:ErrorKind) -> Result<(), Enum> {
+		Err(std::io::Error::from(Kind))?;
+
+		Ok(())
+	}
+
+	#[test]
+	fn ConvertsByKind() {
+		assert!(matches!(Fail(ErrorKind::NotFound), Err(Enum::NotFound(_))));
+
+		assert!(matches!(Fail(ErrorKind::PermissionDenied), Err(Enum::PermissionDenied(_))));
+
+		assert!(matches!(Fail(ErrorKind::UnexpectedEof), Err(Enum::Io(_))));
+	}
+
+	#[test]
+	fn ConvertsMissingFile() {
+		let Open = || -> Result<(), Enum> {
+			std::fs::File::open(std::env::temp_dir().join("Echo-Missing-File"))?;
+
+			Ok(())
+		};
+
+		assert!(matches!(Open(), Err(Enum::NotFound(_))));
+	}
+}
@@ -0,0 +1,77 @@
+/// Describes the kind of JSON value an action parameter or result holds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Enum {
+	/// The JSON `null` value.
+	Null,
+
+	/// A JSON boolean.
+	Bool,
+
+	/// A JSON number, integer or floating point.
+	Number,
+
+	/// A JSON string.
+	String,
+
+	/// A JSON array of any values.
+	Array,
+
+	/// A JSON object of any values.
+	Object,
+
+	/// Any JSON value at all.
+	Any,
+}
+
+impl Enum {
+	/// Determines the kind of a JSON value.
+	///
+	/// # Arguments
+	///
+	/// * `Value` - The value to inspect.
+	///
+	/// # Returns
+	///
+	/// The kind of `Value`, which is never `Any`.
+	pub fn Of(Value:&Value) -> Self {
+		match Value {
+			Value::Null => Enum::Null,
+			Value::Bool(_) => Enum::Bool,
+			Value::Number(_) => Enum::Number,
+			Value::String(_) => Enum::String,
+			Value::Array(_) => Enum::Array,
+			Value::Object(_) => Enum::Object,
+		}
+	}
+
+	/// Checks whether a JSON value is of this kind.
+	///
+	/// # Arguments
+	///
+	/// * `Value` - The value to check.
+	///
+	/// # Returns
+	///
+	/// `true` if `Value` is of this kind, or if this kind is `Any`.
+	pub fn Matches(&self, Value:&Value) -> bool { *self == Enum::Any || *self == Enum::Of(Value) }
+
+	/// Describes this kind as a JSON Schema.
+	///
+	/// # Returns
+	///
+	/// A schema with the matching `type`, or an empty schema for `Any`.
+	pub fn Schema(&self) -> Value {
+		match self {
+			Enum::Null => json!({ "type": "null" }),
+			Enum::Bool => json!({ "type": "boolean" }),
+			Enum::Number => json!({ "type": "number" }),
+			Enum::String => json!({ "type": "string" }),
+			Enum::Array => json!({ "type": "array" }),
+			Enum::Object => json!({ "type": "object" }),
+			Enum::Any => json!({}),
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
@@ -1,5 +1,9 @@
 pub mod Sequence {
 	pub mod Action {
 		pub mod Error;
+
+		pub mod Signature {
+			pub mod Kind;
+		}
 	}
 }
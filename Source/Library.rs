@@ -1,5 +1,4 @@
 #![allow(non_snake_case)]
-#![feature(fn_traits)]
 
 pub mod Struct;
 
@@ -2,7 +2,7 @@
 #[derive(Clone)]
 pub struct Struct {
 	/// The site responsible for processing actions.
-	pub Site:Arc<dyn Site>,
+	pub Site:Arc<dyn crate::Trait::Sequence::Site::Trait>,
 
 	/// The production line containing actions to be executed.
 	pub Production:Arc<Production::Struct>,
@@ -12,6 +12,41 @@ pub struct Struct {
 
 	/// A signal indicating whether the sequence should continue running.
 	pub Time:Signal::Struct<bool>,
+
+	/// A signal indicating whether the sequence should stop once its lines
+	/// are empty.
+	pub Settle:Signal::Struct<bool>,
+
+	/// A signal indicating whether the sequence should stop taking actions
+	/// until it is resumed.
+	pub Rest:Signal::Struct<bool>,
+
+	/// Wakes idle `Run` loops as soon as `Shutdown`, `Settle` or `Resume` is
+	/// called, so they do not finish their poll interval first.
+	pub Halt:Arc<Notify>,
+
+	/// An optional dead-letter channel receiving every action that failed
+	/// for good, together with its final error.
+	pub Grave:Option<crate::Type::Sequence::Action::Grave::Type>,
+
+	/// An optional function rewriting every action before it is processed.
+	pub Shape:Option<crate::Type::Sequence::Action::Shape::Type>,
+
+	/// The position of the next line to take an action from, shared by all
+	/// clones so that a pool takes turns fairly as well.
+	pub Turn:Arc<AtomicUsize>,
+
+	/// The earliest instant the next action may be handed to the site, shared
+	/// by all clones so that a pool respects the `Rate` limit as a whole.
+	pub Slot:Arc<Mutex<Instant>>,
+
+	/// An optional semaphore bounding the total cost of the actions the site
+	/// receives at once, shared by all clones so that it bounds a pool as a
+	/// whole.
+	pub Permit:Option<Arc<Semaphore>>,
+
+	/// The number of permits `Permit` was created with.
+	pub Budget:u32,
 }
 
 impl Struct {
@@ -26,29 +61,337 @@ impl Struct {
 	/// # Returns
 	///
 	/// A new `Struct` instance with the `Time` signal initialized to `false`.
-	pub fn New(Site:Arc<dyn Site>, Production:Arc<Production::Struct>, Life:Life::Struct) -> Self {
-		Struct { Site, Production, Life, Time:Signal::Struct::New(false) }
+	pub fn New(
+		Site:Arc<dyn crate::Trait::Sequence::Site::Trait>,
+		Production:Arc<Production::Struct>,
+		Life:Life::Struct,
+	) -> Self {
+		Struct {
+			Site,
+			Production,
+			Life,
+			Time:Signal::Struct::New(false),
+			Settle:Signal::Struct::New(false),
+			Rest:Signal::Struct::New(false),
+			Halt:Arc::new(Notify::new()),
+			Grave:None,
+			Shape:None,
+			Turn:Arc::new(AtomicUsize::new(0)),
+			Slot:Arc::new(Mutex::new(Instant::now())),
+			Permit:None,
+			Budget:0,
+		}
+	}
+
+	/// Routes actions that failed for good to a dead-letter channel.
+	///
+	/// An action is sent there, with its final error, once its retries are
+	/// exhausted or it failed in a way that is not retried.
+	///
+	/// # Arguments
+	///
+	/// * `Grave` - The sending half of the dead-letter channel.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithGrave(mut self, Grave:crate::Type::Sequence::Action::Grave::Type) -> Self {
+		self.Grave = Some(Grave);
+
+		self
+	}
+
+	/// Rewrites every action with `Shape` before it is processed.
+	///
+	/// The function sees each action as it is taken off a line and returns the
+	/// action to process in its place, so it is retried and dead-lettered as
+	/// rewritten.
+	///
+	/// # Arguments
+	///
+	/// * `Shape` - The function rewriting actions.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithShape(mut self, Shape:crate::Type::Sequence::Action::Shape::Type) -> Self {
+		self.Shape = Some(Shape);
+
+		self
+	}
+
+	/// Lets the site receive actions worth at most `Limit` at once.
+	///
+	/// Every action in flight holds as many permits as its `Action::Cost`,
+	/// which is `1` unless the action reports otherwise, so with the default
+	/// cost this bounds the number of actions in flight. An action costing
+	/// more than `Limit` waits for every permit and then runs alone.
+	///
+	/// The bound holds across every clone made afterwards, so a pool of any
+	/// size spawned from this sequence stays within `Limit`. Permits are only
+	/// held while the site receives an action, not while an action waits to
+	/// be retried.
+	///
+	/// A `Limit` of `0` removes the bound, as if `WithLimit` was never called,
+	/// rather than letting no action through.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - The maximum total cost of the actions in flight, or `0` for
+	///   no bound.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithLimit(mut self, Limit:u32) -> Self {
+		self.Permit = (Limit > 0).then(|| Arc::new(Semaphore::new(Limit as usize)));
+
+		self.Budget = Limit;
+
+		self
 	}
 
 	/// Runs the sequence, processing actions until the `Time` signal is set to
-	/// true.
+	/// true, or until the lines are empty once the `Settle` signal is set.
 	///
 	/// This method continuously checks for new actions in the `Work` queue and
 	/// processes them. If an error occurs during processing, it logs the
-	/// error.
-	pub async fn Run(&self) {
+	/// error. Actions are taken from `Production` and the `Life.Karma` lines
+	/// in turn, as described in `Take`.
+	///
+	/// Every processed action is counted as described in `Process`, and also
+	/// in the summary returned once the loop stops. While the lines are empty,
+	/// the loop waits for a new action, but polls again at least every
+	/// `Interval` milliseconds (`Interval` in `Life.Fate`, 100 by default).
+	/// While the `Rest` signal is set, or while `Site` reports that it is not
+	/// `Healthy`, no action is taken and the loop waits the same way for
+	/// `Resume`, or for the site to recover.
+	///
+	/// # Returns
+	///
+	/// The number of actions the loop processed, succeeded and failed.
+	pub async fn Run(&self) -> Summary::Struct {
+		let Interval = self.Life.Fate.get_int("Interval").unwrap_or(100) as u64;
+
+		let mut Summary = Summary::Struct::default();
+
 		while !self.Time.Get().await {
-			if let Some(Action) = self.Production.Do().await {
-				match self.Again(Action).await {
-					Ok(_) => {},
-					Err(e) => error!("Error processing action: {}", e),
+			if self.Rest.Get().await || !self.Site.Healthy().await {
+				select! {
+					_ = sleep(Duration::from_millis(Interval)) => {},
+					_ = self.Halt.notified() => {},
+				}
+			} else if let Some(Action) = self.Take().await {
+				Summary.Processed += 1;
+
+				if self.Process(Action).await.is_ok() {
+					Summary.Succeeded += 1;
+				} else {
+					Summary.Failed += 1;
 				}
+			} else if self.Settle.Get().await {
+				break;
 			} else {
-				// Add a small delay to prevent tight looping when there are no
-				// actions
-				sleep(std::time::Duration::from_millis(100)).await;
+				// Wait for a new action or a shutdown, falling back to a small
+				// delay so a missed notification cannot stall the loop
+				select! {
+					_ = self.Production.Wait() => {},
+					_ = sleep(Duration::from_millis(Interval)) => {},
+					_ = self.Halt.notified() => {},
+				}
+			}
+		}
+
+		Summary
+	}
+
+	/// Executes the given actions one after another, bypassing `Production`.
+	///
+	/// Each action is processed as in `Run`, including `Shape`, retries,
+	/// timeouts, metrics, logging and `Grave`, but nothing is queued or
+	/// spawned, which suits scripts and tests.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The actions to be executed.
+	///
+	/// # Returns
+	///
+	/// The result of each action, in the same order as `Action`.
+	pub async fn Execute(
+		&self,
+		Action:Vec<Box<dyn crate::Trait::Sequence::Action::Trait>>,
+	) -> Vec<Result<(), crate::Enum::Sequence::Action::Error::Enum>> {
+		let mut Result = Vec::with_capacity(Action.len());
+
+		for Action in Action {
+			Result.push(self.Process(Action).await);
+		}
+
+		Result
+	}
+
+	/// Processes one queued action and returns its result, for callers that
+	/// drive the sequence themselves instead of spawning `Run`.
+	///
+	/// The action is taken as in `Run` and processed the same way, including
+	/// retries, metrics, logging and `Grave`, but the signals are not checked
+	/// and nothing waits for new actions.
+	///
+	/// # Returns
+	///
+	/// The final result of the action, or `None` if every line is empty.
+	pub async fn Step(&self) -> Option<Result<(), crate::Enum::Sequence::Action::Error::Enum>> {
+		let Action = self.Take().await?;
+
+		Some(self.Process(Action).await)
+	}
+
+	/// Spawns `Force` tasks that each `Run` a clone of this sequence.
+	///
+	/// All tasks share the same `Production` line and `Life` context. The line
+	/// is only locked while an action is taken off it, so actions are received
+	/// concurrently.
+	///
+	/// # Arguments
+	///
+	/// * `Force` - The number of tasks to spawn.
+	///
+	/// # Returns
+	///
+	/// The `JoinHandle` of every spawned task, which finish after `Shutdown`
+	/// with the summary of their loop.
+	pub fn Spawn(&self, Force:usize) -> Vec<JoinHandle<Summary::Struct>> {
+		(0..Force)
+			.map(|_| {
+				let Sequence = self.clone();
+
+				tokio::spawn(async move { Sequence.Run().await })
+			})
+			.collect()
+	}
+
+	/// Takes the next action, taking turns between the production lines.
+	///
+	/// The lines are `Production` followed by every line in `Life.Karma`,
+	/// ordered by key. Starting after the line served last, each line is asked
+	/// for an action in turn, so a busy line cannot starve the others. Only
+	/// `Production` wakes an idle `Run`, so actions assigned to a `Karma` line
+	/// are picked up within the poll interval.
+	///
+	/// # Returns
+	///
+	/// The next action, or `None` if every line is empty.
+	async fn Take(&self) -> Option<Box<dyn crate::Trait::Sequence::Action::Trait>> {
+		let mut Karma:Vec<(String, Arc<Production::Struct>)> = self
+			.Life
+			.Karma
+			.iter()
+			.map(|Entry| (Entry.key().clone(), Entry.value().clone()))
+			.collect();
+
+		Karma.sort_by(|Left, Right| Left.0.cmp(&Right.0));
+
+		let Line:Vec<Arc<Production::Struct>> = std::iter::once(self.Production.clone())
+			.chain(Karma.into_iter().map(|(_, Line)| Line))
+			.collect();
+
+		let Start = self.Turn.load(Ordering::Relaxed);
+
+		for Offset in 0..Line.len() {
+			let Index = (Start + Offset) % Line.len();
+
+			if let Some(Action) = Line[Index].Do().await {
+				self.Turn.store(Index + 1, Ordering::Relaxed);
+
+				return Some(Action);
 			}
 		}
+
+		None
+	}
+
+	/// Processes one action taken off the production line.
+	///
+	/// The action is first rewritten by `Shape`, if one is set. It is then
+	/// executed with retry logic, and an action that failed for good is
+	/// logged and sent to `Grave`, if one is set. If the receiving half of
+	/// `Grave` was dropped, the action is counted under
+	/// `Echo.Sequence.Dropped` and a warning reports how many actions are
+	/// still queued, while the loop carries on with them.
+	///
+	/// Every processed action is counted through the `metrics` facade under
+	/// `Echo.Sequence.Processed`, `Echo.Sequence.Succeeded` and
+	/// `Echo.Sequence.Failed`, and the total time spent on actions, retries
+	/// included, under `Echo.Sequence.Busy` (in microseconds).
+	///
+	/// The start and the success of every action are logged under the
+	/// `Echo::Sequence` target at the level named by `Level` in `Life.Fate`
+	/// (`debug` by default), and a failure is logged there as an error. Each
+	/// message names the action, or calls it `unnamed` if it has no name.
+	///
+	/// # Returns
+	///
+	/// The final result of the action.
+	async fn Process(
+		&self,
+		Action:Box<dyn crate::Trait::Sequence::Action::Trait>,
+	) -> Result<(), crate::Enum::Sequence::Action::Error::Enum> {
+		let Action = match &self.Shape {
+			Some(Shape) => Shape(Action),
+			None => Action,
+		};
+
+		let Level = self
+			.Life
+			.Fate
+			.get_string("Level")
+			.ok()
+			.and_then(|Level| Level.parse::<Level>().ok())
+			.unwrap_or(Level::Debug);
+
+		let Name = Action.Name().await.unwrap_or_else(|| "unnamed".to_string());
+
+		log!(target: "Echo::Sequence", Level, "Action {} started", Name);
+
+		let Start = Instant::now();
+
+		let Result = self.Again(Action.as_ref()).await;
+
+		let Elapsed = Start.elapsed();
+
+		counter!("Echo.Sequence.Busy").increment(Elapsed.as_micros() as u64);
+
+		counter!("Echo.Sequence.Processed").increment(1);
+
+		match Result {
+			Ok(_) => {
+				counter!("Echo.Sequence.Succeeded").increment(1);
+
+				log!(target: "Echo::Sequence", Level, "Action {} succeeded in {:?}", Name, Elapsed);
+
+				Ok(())
+			},
+			Err(e) => {
+				counter!("Echo.Sequence.Failed").increment(1);
+
+				error!(target: "Echo::Sequence", "Action {} failed in {:?}: {}", Name, Elapsed, e);
+
+				if let Some(Grave) = &self.Grave
+					&& Grave.send((Action, e.clone())).is_err()
+				{
+					counter!("Echo.Sequence.Dropped").increment(1);
+
+					warn!(
+						target: "Echo::Sequence",
+						"Dead-letter channel is closed, dropping failed action with {} queued",
+						self.Production.Len().await
+					);
+				}
+
+				Err(e)
+			},
+		}
 	}
 
 	/// Attempts to execute an action with retry logic.
@@ -63,30 +406,75 @@ impl Struct {
 	///
 	/// This method will retry the action execution up to a maximum number of
 	/// times (defined by `End` in `Life.Fate`) with exponential backoff and
-	/// jitter.
+	/// jitter. The delay before retry `n` is `Backoff * 2^n` milliseconds
+	/// (`Backoff` in `Life.Fate`, 1000 by default) plus up to one second of
+	/// jitter, and at most `Ceiling` milliseconds (`Ceiling` in `Life.Fate`,
+	/// one minute by default). Each attempt is bounded by the timeout
+	/// described in `Receive`. A cancelled or expired action is never retried,
+	/// and neither is one that failed with a `Conflict`, since a stale
+	/// precondition fails the same way on every attempt. Retries and giving up
+	/// are logged under the `Echo::Sequence` target.
 	async fn Again(
 		&self,
-		Action:Box<dyn crate::Trait::Sequence::Action::Trait>,
+		Action:&dyn crate::Trait::Sequence::Action::Trait,
 	) -> Result<(), crate::Enum::Sequence::Action::Error::Enum> {
 		let End = self.Life.Fate.get_int("End").unwrap_or(3) as u32;
 
+		let Backoff = self.Life.Fate.get_int("Backoff").unwrap_or(1000) as u64;
+
+		let Ceiling = self.Life.Fate.get_int("Ceiling").unwrap_or(60_000) as u64;
+
+		let Name = Action.Name().await.unwrap_or_else(|| "unnamed".to_string());
+
 		let mut Attempt = 0;
 
 		loop {
-			match self.Site.Receive(Action.Clone(), &self.Life).await {
-				Ok(_) => return Ok(()),
+			match self.Receive(Action.Clone()).await {
+				Ok(_) => {
+					if Attempt > 0 {
+						info!(
+							target: "Echo::Sequence",
+							"Action {} succeeded on attempt {} of {}",
+							Name,
+							Attempt + 1,
+							End
+						);
+					}
+
+					return Ok(());
+				},
+				Err(
+					e @ (crate::Enum::Sequence::Action::Error::Enum::Cancellation(_)
+					| crate::Enum::Sequence::Action::Error::Enum::Expired(_)
+					| crate::Enum::Sequence::Action::Error::Enum::Conflict(_)),
+				) => {
+					return Err(e);
+				},
 				Err(e) => {
 					Attempt += 1;
 
 					if Attempt >= End {
+						warn!(
+							target: "Echo::Sequence",
+							"Action {} failed on attempt {} of {}, giving up",
+							Name, Attempt, End
+						);
+
 						return Err(e);
 					}
 
-					let Again = Duration::from_secs(
-						2u64.pow(Attempt) + rand::thread_rng().gen_range(0..1000),
+					let Again = Duration::from_millis(
+						Backoff
+							.saturating_mul(2u64.saturating_pow(Attempt))
+							.saturating_add(rand::rng().random_range(0..1000))
+							.min(Ceiling),
 					);
 
-					warn!("Action failed, retrying in {:?}. Attempt {} of {}", Again, Attempt, End);
+					warn!(
+						target: "Echo::Sequence",
+						"Action {} failed, retrying in {:?}. Attempt {} of {}",
+						Name, Again, Attempt, End
+					);
 
 					sleep(Again).await;
 				},
@@ -96,23 +484,1100 @@ impl Struct {
 		// unreachable!("Loop should have returned or errored")
 	}
 
+	/// Hands a single action to the site.
+	///
+	/// The action first waits for its permits, if `WithLimit` set a bound, and
+	/// is then paced as described in `Pace`. If `Timeout` is set in `Life.Fate`
+	/// (in milliseconds), an action that takes longer is abandoned and
+	/// reported as a `Timeout` error. The time spent in the site is logged
+	/// under the `Echo::Sequence` target for every attempt, and the number of
+	/// actions in the site is tracked through the `metrics` facade under the
+	/// `Echo.Sequence.Flight` gauge.
+	async fn Receive(
+		&self,
+		Action:Box<dyn crate::Trait::Sequence::Action::Trait>,
+	) -> Result<(), crate::Enum::Sequence::Action::Error::Enum> {
+		let _Permit = match &self.Permit {
+			Some(Permit) => {
+				let Cost = Action.Cost().await.clamp(1, self.Budget.max(1));
+
+				Some(Permit.acquire_many(Cost).await.map_err(|_Error| {
+					crate::Enum::Sequence::Action::Error::Enum::Cancellation(_Error.to_string())
+				})?)
+			},
+			None => None,
+		};
+
+		self.Pace().await;
+
+		gauge!("Echo.Sequence.Flight").increment(1.0);
+
+		let Start = Instant::now();
+
+		let Result = match self.Life.Fate.get_int("Timeout") {
+			Ok(Limit) => {
+				let Limit = Duration::from_millis(Limit as u64);
+
+				timeout(Limit, self.Site.Receive(Action, &self.Life)).await.unwrap_or_else(|_| {
+					Err(crate::Enum::Sequence::Action::Error::Enum::Timeout(format!(
+						"Action exceeded {:?}",
+						Limit
+					)))
+				})
+			},
+			Err(_) => self.Site.Receive(Action, &self.Life).await,
+		};
+
+		gauge!("Echo.Sequence.Flight").decrement(1.0);
+
+		debug!(
+			target: "Echo::Sequence",
+			"Action {} in {:?}",
+			if Result.is_ok() { "completed" } else { "failed" },
+			Start.elapsed()
+		);
+
+		Result
+	}
+
+	/// Waits until the site may receive another action.
+	///
+	/// If `Rate` is set in `Life.Fate` (in actions per second), every attempt
+	/// reserves the next free slot, `1 / Rate` seconds after the previous one,
+	/// and sleeps until it comes, so actions are spread evenly instead of
+	/// bursting. Without a positive `Rate`, this returns immediately.
+	async fn Pace(&self) {
+		let Rate = match self.Life.Fate.get_float("Rate") {
+			Ok(Rate) if Rate > 0.0 => Rate,
+			_ => return,
+		};
+
+		let Now = Instant::now();
+
+		let Wait = {
+			let mut Slot = self.Slot.lock().await;
+
+			let Next = (*Slot).max(Now);
+
+			*Slot = Next + Duration::from_secs_f64(1.0 / Rate);
+
+			Next - Now
+		};
+
+		if !Wait.is_zero() {
+			sleep(Wait).await;
+		}
+	}
+
 	/// Signals the sequence to shut down by setting the `Time` signal to true.
-	pub async fn Shutdown(&self) { self.Time.Set(true).await; }
+	///
+	/// Any `Run` loop idling between polls is woken immediately. A loop busy
+	/// with an action stops after it, leaving the remaining actions queued;
+	/// use `Settle` to finish them first.
+	pub async fn Shutdown(&self) {
+		self.Time.Set(true).await;
+
+		self.Halt.notify_waiters();
+	}
+
+	/// Signals the sequence to pause by setting the `Rest` signal to true.
+	///
+	/// `Run` loops finish the action they are processing, then stop taking
+	/// actions until `Resume` is called. Actions can still be assigned in the
+	/// meantime and stay queued.
+	pub async fn Pause(&self) { self.Rest.Set(true).await; }
+
+	/// Signals a paused sequence to carry on by setting the `Rest` signal to
+	/// false.
+	///
+	/// Any `Run` loop waiting while paused is woken immediately and takes the
+	/// actions queued in the meantime.
+	pub async fn Resume(&self) {
+		self.Rest.Set(false).await;
+
+		self.Halt.notify_waiters();
+	}
+
+	/// Signals the sequence to finish the queued actions and then stop, by
+	/// setting the `Settle` signal to true.
+	///
+	/// `Run` loops keep processing actions, including ones still being
+	/// assigned, and return once every line is empty. Any `Run` loop idling
+	/// between polls is woken immediately.
+	pub async fn Settle(&self) {
+		self.Settle.Set(true).await;
+
+		self.Halt.notify_waiters();
+	}
 }
 
 pub use std::sync::Arc;
-use std::time::Duration;
+use std::{
+	sync::atomic::{AtomicUsize, Ordering},
+	time::{Duration, Instant},
+};
 
-use log::{error, warn};
+use log::{Level, debug, error, info, log, warn};
+use metrics::{counter, gauge};
 use rand::Rng;
 pub use tokio::sync::Mutex;
-use tokio::time::sleep;
+use tokio::{
+	select,
+	sync::{Notify, Semaphore},
+	task::JoinHandle,
+	time::{sleep, timeout},
+};
 
 pub mod Action;
+#[cfg(test)]
+pub mod Fixture;
 pub mod Life;
 pub mod Plan;
 pub mod Production;
 pub mod Signal;
+pub mod Site;
+pub mod Summary;
 pub mod Vector;
 
-use crate::Trait::Sequence::Site::Trait as Site;
+#[cfg(test)]
+mod Test {
+	use std::{
+		cell::RefCell,
+		sync::{
+			Once,
+			atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+		},
+		time::Duration,
+	};
+
+	use async_trait::async_trait;
+	use dashmap::DashMap;
+	use metrics::{
+		Counter, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit,
+		set_default_local_recorder,
+	};
+	use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+
+	use super::{Arc, Struct};
+	use crate::{
+		Enum::Sequence::Action::Error::Enum as Error,
+		Struct::Sequence::{
+			Fixture::{self, Probe},
+			Life::Struct as Life,
+			Production,
+			Site,
+		},
+		Trait::Sequence::Action::Trait as Action,
+	};
+
+	/// An action that always fails.
+	struct Broken;
+
+	#[async_trait]
+	impl Action for Broken {
+		async fn Execute(&self, _Context:&Life) -> Result<(), Error> {
+			Err(Error::Execution("Broken".to_string()))
+		}
+
+		fn Clone(&self) -> Box<dyn Action> { Box::new(Broken) }
+	}
+
+	/// An action that fails its first `Fail` attempts, counting every one.
+	#[derive(Clone)]
+	struct Flaky {
+		Attempt:Arc<AtomicU32>,
+
+		Fail:u32,
+	}
+
+	#[async_trait]
+	impl Action for Flaky {
+		async fn Execute(&self, _Context:&Life) -> Result<(), Error> {
+			if self.Attempt.fetch_add(1, Ordering::SeqCst) < self.Fail {
+				return Err(Error::Execution("Flaky".to_string()));
+			}
+
+			Ok(())
+		}
+
+		fn Clone(&self) -> Box<dyn Action> { Box::new(Clone::clone(self)) }
+	}
+
+	/// An action whose precondition never holds, counting every attempt.
+	#[derive(Clone)]
+	struct Stale(Arc<AtomicU32>);
+
+	#[async_trait]
+	impl Action for Stale {
+		async fn Execute(&self, _Context:&Life) -> Result<(), Error> {
+			self.0.fetch_add(1, Ordering::SeqCst);
+
+			Err(Error::Conflict("Stale".to_string()))
+		}
+
+		fn Clone(&self) -> Box<dyn Action> { Box::new(Clone::clone(self)) }
+	}
+
+	/// An action that takes its time.
+	struct Sleep(Duration);
+
+	#[async_trait]
+	impl Action for Sleep {
+		async fn Execute(&self, _Context:&Life) -> Result<(), Error> {
+			tokio::time::sleep(self.0).await;
+
+			Ok(())
+		}
+
+		fn Clone(&self) -> Box<dyn Action> { Box::new(Sleep(self.0)) }
+	}
+
+	/// An action that stays in flight for a while, recording the most actions
+	/// in flight at once, weighed by their cost.
+	#[derive(Clone)]
+	struct Busy {
+		Current:Arc<AtomicU32>,
+
+		Peak:Arc<AtomicU32>,
+
+		Cost:u32,
+	}
+
+	impl Busy {
+		fn New() -> Self {
+			Busy { Current:Arc::new(AtomicU32::new(0)), Peak:Arc::new(AtomicU32::new(0)), Cost:1 }
+		}
+
+		fn WithCost(&self, Cost:u32) -> Self { Busy { Cost, ..Clone::clone(self) } }
+	}
+
+	#[async_trait]
+	impl Action for Busy {
+		async fn Execute(&self, _Context:&Life) -> Result<(), Error> {
+			let Current = self.Current.fetch_add(self.Cost, Ordering::SeqCst) + self.Cost;
+
+			self.Peak.fetch_max(Current, Ordering::SeqCst);
+
+			tokio::time::sleep(Duration::from_millis(50)).await;
+
+			self.Current.fetch_sub(self.Cost, Ordering::SeqCst);
+
+			Ok(())
+		}
+
+		fn Clone(&self) -> Box<dyn Action> { Box::new(Clone::clone(self)) }
+
+		async fn Cost(&self) -> u32 { self.Cost }
+	}
+
+	/// An action that reports on a channel once it runs.
+	struct Signal(UnboundedSender<()>);
+
+	#[async_trait]
+	impl Action for Signal {
+		async fn Execute(&self, _Context:&Life) -> Result<(), Error> {
+			let _ = self.0.send(());
+
+			Ok(())
+		}
+
+		fn Clone(&self) -> Box<dyn Action> { Box::new(Signal(self.0.clone())) }
+	}
+
+	/// An action that only succeeds in a context whose `Fate` sets `Open`.
+	struct Gate;
+
+	#[async_trait]
+	impl Action for Gate {
+		async fn Execute(&self, Context:&Life) -> Result<(), Error> {
+			match Context.Fate.get_bool("Open") {
+				Ok(true) => Ok(()),
+				_ => Err(Error::PermissionDenied("Gate is closed".to_string())),
+			}
+		}
+
+		fn Clone(&self) -> Box<dyn Action> { Box::new(Gate) }
+	}
+
+	/// An action that appends its tag to a shared log once it runs.
+	#[derive(Clone)]
+	struct Tag(&'static str, Arc<std::sync::Mutex<Vec<&'static str>>>);
+
+	#[async_trait]
+	impl Action for Tag {
+		async fn Execute(&self, _Context:&Life) -> Result<(), Error> {
+			self.1.lock().unwrap().push(self.0);
+
+			Ok(())
+		}
+
+		async fn Name(&self) -> Option<String> { Some(self.0.to_string()) }
+
+		fn Clone(&self) -> Box<dyn Action> { Box::new(Clone::clone(self)) }
+	}
+
+	/// A site that executes actions directly while its flag reports healthy.
+	struct Mount(Arc<AtomicBool>);
+
+	#[async_trait]
+	impl crate::Trait::Sequence::Site::Trait for Mount {
+		async fn Receive(&self, Action:Box<dyn Action>, Context:&Life) -> Result<(), Error> {
+			Action.Execute(Context).await
+		}
+
+		async fn Healthy(&self) -> bool { self.0.load(Ordering::SeqCst) }
+	}
+
+	/// A metrics recorder that keeps every counter in memory.
+	#[derive(Default)]
+	struct Tally(DashMap<String, Arc<AtomicU64>>);
+
+	impl Tally {
+		fn Get(&self, Name:&str) -> u64 {
+			self.0.get(Name).map_or(0, |Counter| Counter.load(Ordering::SeqCst))
+		}
+	}
+
+	impl Recorder for Tally {
+		fn describe_counter(&self, _Name:KeyName, _Unit:Option<Unit>, _About:SharedString) {}
+
+		fn describe_gauge(&self, _Name:KeyName, _Unit:Option<Unit>, _About:SharedString) {}
+
+		fn describe_histogram(&self, _Name:KeyName, _Unit:Option<Unit>, _About:SharedString) {}
+
+		fn register_counter(&self, Name:&Key, _Metadata:&Metadata<'_>) -> Counter {
+			Counter::from_arc(self.0.entry(Name.name().to_string()).or_default().clone())
+		}
+
+		fn register_gauge(&self, _Name:&Key, _Metadata:&Metadata<'_>) -> Gauge { Gauge::noop() }
+
+		fn register_histogram(&self, _Name:&Key, _Metadata:&Metadata<'_>) -> Histogram {
+			Histogram::noop()
+		}
+	}
+
+	thread_local! {
+		/// The log records of the current thread, kept by `Capture`.
+		static JOURNAL:RefCell<Vec<(log::Level, String, String)>> =
+			const { RefCell::new(Vec::new()) };
+	}
+
+	/// A logger keeping the records of every thread apart, so tests running at
+	/// once do not see each other's records.
+	struct Capture;
+
+	impl log::Log for Capture {
+		fn enabled(&self, _Metadata:&log::Metadata<'_>) -> bool { true }
+
+		fn log(&self, Record:&log::Record<'_>) {
+			let Kept = (Record.level(), Record.target().to_string(), Record.args().to_string());
+
+			JOURNAL.with_borrow_mut(|Record| Record.push(Kept));
+		}
+
+		fn flush(&self) {}
+	}
+
+	impl Capture {
+		/// Installs the logger, once per process, and forgets the records of
+		/// the current thread so far.
+		fn Start() {
+			static INSTALL:Once = Once::new();
+
+			INSTALL.call_once(|| {
+				log::set_logger(&Capture).unwrap();
+
+				log::set_max_level(log::LevelFilter::Trace);
+			});
+
+			JOURNAL.with_borrow_mut(Vec::clear);
+		}
+
+		/// Takes the records of the current thread under `Target`.
+		fn Take(Target:&str) -> Vec<(log::Level, String)> {
+			JOURNAL.with_borrow_mut(|Record| {
+				Record
+					.drain(..)
+					.filter(|(_, Name, _)| Name == Target)
+					.map(|(Level, _, Message)| (Level, Message))
+					.collect()
+			})
+		}
+	}
+
+	fn Sequence() -> Struct { SequenceWith(config::Config::default()) }
+
+	fn Fate(Setting:&[(&str, i64)]) -> config::Config {
+		Setting
+			.iter()
+			.fold(config::Config::builder(), |Builder, (Key, Value)| {
+				Builder.set_override(*Key, *Value).unwrap()
+			})
+			.build()
+			.unwrap()
+	}
+
+	fn SequenceWith(Fate:config::Config) -> Struct {
+		Struct::New(Arc::new(Site::Struct), Arc::new(Production::Struct::New()), Fixture::Life(Fate))
+	}
+
+	#[tokio::test]
+	async fn LimitZeroIsUnbounded() {
+		let Sequence = Sequence().WithLimit(0);
+
+		assert!(Sequence.Permit.is_none());
+
+		let Result =
+			tokio::time::timeout(Duration::from_secs(5), Sequence.Execute(vec![Box::new(Probe)]))
+				.await
+				.expect("action waited for a permit");
+
+		assert!(Result[0].is_ok());
+	}
+
+	#[tokio::test]
+	async fn BackoffStopsAtCeiling() {
+		let Sequence = SequenceWith(Fate(&[("End", 2), ("Backoff", i64::MAX), ("Ceiling", 10)]));
+
+		let Result =
+			tokio::time::timeout(Duration::from_secs(5), Sequence.Execute(vec![Box::new(Broken)]))
+				.await
+				.expect("retry waited past the ceiling");
+
+		assert!(matches!(Result[0], Err(Error::Execution(_))));
+	}
+
+	#[tokio::test]
+	async fn ShutdownStopsRun() {
+		// A long poll interval, so only the shutdown itself can wake the loop
+		let Sequence = SequenceWith(Fate(&[("Interval", 60_000)]));
+
+		let Run = tokio::spawn({
+			let Sequence = Sequence.clone();
+
+			async move { Sequence.Run().await }
+		});
+
+		tokio::time::sleep(Duration::from_millis(20)).await;
+
+		Sequence.Shutdown().await;
+
+		tokio::time::timeout(Duration::from_secs(5), Run)
+			.await
+			.expect("Run kept going after Shutdown")
+			.unwrap();
+	}
+
+	#[tokio::test]
+	async fn AssignWakesRun() {
+		let Sequence = SequenceWith(Fate(&[("Interval", 60_000)]));
+
+		let Run = tokio::spawn({
+			let Sequence = Sequence.clone();
+
+			async move { Sequence.Run().await }
+		});
+
+		tokio::time::sleep(Duration::from_millis(20)).await;
+
+		let (Sender, mut Receiver) = unbounded_channel();
+
+		Sequence.Production.Assign(Box::new(Signal(Sender))).await;
+
+		tokio::time::timeout(Duration::from_millis(50), Receiver.recv())
+			.await
+			.expect("assigned action waited for the poll interval");
+
+		Sequence.Shutdown().await;
+
+		Run.await.unwrap();
+	}
+
+	#[tokio::test]
+	async fn TimeoutAbandonsSlowAction() {
+		let Sequence = SequenceWith(Fate(&[("End", 1), ("Timeout", 20)]));
+
+		let Result = Sequence.Execute(vec![Box::new(Sleep(Duration::from_secs(3600)))]).await;
+
+		assert!(matches!(Result[0], Err(Error::Timeout(_))));
+
+		let Result = Sequence.Execute(vec![Box::new(Sleep(Duration::ZERO))]).await;
+
+		assert!(Result[0].is_ok());
+	}
+
+	#[tokio::test]
+	async fn SpawnRunsInParallel() {
+		let Sequence = Sequence();
+
+		let Busy = Busy::New();
+
+		for _ in 0..4 {
+			Sequence.Production.Assign(Box::new(Busy.clone())).await;
+		}
+
+		let Pool = Sequence.Spawn(4);
+
+		Sequence.Settle().await;
+
+		for Run in Pool {
+			Run.await.unwrap();
+		}
+
+		assert!(Busy.Peak.load(Ordering::SeqCst) > 1);
+	}
+
+	#[tokio::test]
+	async fn RetrySucceedsAfterFailures() {
+		let Sequence = SequenceWith(Fate(&[("End", 3), ("Ceiling", 0)]));
+
+		let Flaky = Flaky { Attempt:Arc::new(AtomicU32::new(0)), Fail:2 };
+
+		let Result = Sequence.Execute(vec![Box::new(Flaky.clone())]).await;
+
+		assert!(Result[0].is_ok());
+
+		assert_eq!(Flaky.Attempt.load(Ordering::SeqCst), 3);
+	}
+
+	#[tokio::test]
+	async fn RetryGivesUpAfterEnd() {
+		let Sequence = SequenceWith(Fate(&[("End", 3), ("Ceiling", 0)]));
+
+		let Flaky = Flaky { Attempt:Arc::new(AtomicU32::new(0)), Fail:u32::MAX };
+
+		let Result = Sequence.Execute(vec![Box::new(Flaky.clone())]).await;
+
+		assert!(matches!(Result[0], Err(Error::Execution(_))));
+
+		assert_eq!(Flaky.Attempt.load(Ordering::SeqCst), 3);
+	}
+
+	#[tokio::test]
+	async fn ConflictIsNotRetried() {
+		let Sequence = SequenceWith(Fate(&[("End", 3), ("Ceiling", 0)]));
+
+		let Attempt = Arc::new(AtomicU32::new(0));
+
+		let Result = Sequence.Execute(vec![Box::new(Stale(Attempt.clone()))]).await;
+
+		assert!(matches!(Result[0], Err(Error::Conflict(_))));
+
+		assert_eq!(Attempt.load(Ordering::SeqCst), 1);
+	}
+
+	#[tokio::test]
+	async fn CountsProcessedActions() {
+		let Sequence = SequenceWith(Fate(&[("End", 1)]));
+
+		Sequence.Production.Assign(Box::new(Probe)).await;
+
+		Sequence.Production.Assign(Box::new(Sleep(Duration::from_millis(10)))).await;
+
+		Sequence.Production.Assign(Box::new(Broken)).await;
+
+		let Recorder = Tally::default();
+
+		let _Guard = set_default_local_recorder(&Recorder);
+
+		while Sequence.Step().await.is_some() {}
+
+		assert_eq!(Recorder.Get("Echo.Sequence.Processed"), 3);
+
+		assert_eq!(Recorder.Get("Echo.Sequence.Succeeded"), 2);
+
+		assert_eq!(Recorder.Get("Echo.Sequence.Failed"), 1);
+
+		assert!(Recorder.Get("Echo.Sequence.Busy") >= 10_000);
+	}
+
+	#[tokio::test]
+	async fn ContextDecidesResult() {
+		let Closed = SequenceWith(Fate(&[("End", 1)]));
+
+		let Open = SequenceWith(Fate(&[("End", 1), ("Open", 1)]));
+
+		assert!(matches!(
+			Closed.Execute(vec![Box::new(Gate)]).await[0],
+			Err(Error::PermissionDenied(_))
+		));
+
+		assert!(Open.Execute(vec![Box::new(Gate)]).await[0].is_ok());
+	}
+
+	#[tokio::test]
+	async fn ExecuteKeepsOrder() {
+		let Sequence = SequenceWith(Fate(&[("End", 1)]));
+
+		let Result = Sequence
+			.Execute(vec![Box::new(Probe), Box::new(Broken), Box::new(Probe), Box::new(Broken)])
+			.await;
+
+		assert_eq!(
+			Result.iter().map(Result::is_ok).collect::<Vec<_>>(),
+			[true, false, true, false]
+		);
+
+		assert!(Sequence.Execute(vec![]).await.is_empty());
+	}
+
+	#[tokio::test]
+	async fn GraveReceivesFailedAction() {
+		let (Grave, mut Buried) = unbounded_channel();
+
+		let Sequence = SequenceWith(Fate(&[("End", 1)])).WithGrave(Grave);
+
+		Sequence.Production.Assign(Box::new(Probe)).await;
+
+		Sequence.Production.Assign(Box::new(Broken)).await;
+
+		Sequence.Settle().await;
+
+		let Summary = Sequence.Run().await;
+
+		assert_eq!((Summary.Succeeded, Summary.Failed), (1, 1));
+
+		let (Action, Failure) = Buried.try_recv().unwrap();
+
+		assert!(matches!(Failure, Error::Execution(_)));
+
+		assert!(Action.Execute(&Sequence.Life).await.is_err());
+
+		assert!(Buried.try_recv().is_err());
+	}
+
+	#[tokio::test]
+	async fn IntervalBoundsKarmaPickup() {
+		for (Interval, Expected) in [(10, true), (60_000, false)] {
+			let Sequence = SequenceWith(Fate(&[("Interval", Interval)]));
+
+			let Line = Arc::new(Production::Struct::New());
+
+			Sequence.Life.Karma.insert("Other".to_string(), Line.clone());
+
+			let Run = tokio::spawn({
+				let Sequence = Sequence.clone();
+
+				async move { Sequence.Run().await }
+			});
+
+			tokio::time::sleep(Duration::from_millis(20)).await;
+
+			let (Sender, mut Receiver) = unbounded_channel();
+
+			// Karma lines do not wake an idle loop, so only the poll finds this
+			Line.Assign(Box::new(Signal(Sender))).await;
+
+			let Picked = tokio::time::timeout(Duration::from_millis(200), Receiver.recv()).await;
+
+			assert_eq!(Picked.is_ok(), Expected);
+
+			Sequence.Shutdown().await;
+
+			Run.await.unwrap();
+		}
+	}
+
+	#[tokio::test]
+	async fn LinesTakeTurns() {
+		let Sequence = Sequence();
+
+		let Log = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+		let Line = Arc::new(Production::Struct::New());
+
+		Sequence.Life.Karma.insert("Quiet".to_string(), Line.clone());
+
+		for _ in 0..4 {
+			Sequence.Production.Assign(Box::new(Tag("Busy", Log.clone()))).await;
+		}
+
+		for _ in 0..2 {
+			Line.Assign(Box::new(Tag("Quiet", Log.clone()))).await;
+		}
+
+		while Sequence.Step().await.is_some() {}
+
+		assert_eq!(*Log.lock().unwrap(), ["Busy", "Quiet", "Busy", "Quiet", "Busy", "Busy"]);
+	}
+
+	#[tokio::test]
+	async fn SettleDrainsButShutdownLeavesQueue() {
+		let Settled = Sequence();
+
+		let Stopped = Sequence();
+
+		for Sequence in [&Settled, &Stopped] {
+			for _ in 0..3 {
+				Sequence.Production.Assign(Box::new(Probe)).await;
+			}
+		}
+
+		Settled.Settle().await;
+
+		assert_eq!(Settled.Run().await.Processed, 3);
+
+		assert_eq!(Settled.Production.Len().await, 0);
+
+		Stopped.Shutdown().await;
+
+		assert_eq!(Stopped.Run().await.Processed, 0);
+
+		assert_eq!(Stopped.Production.Len().await, 3);
+	}
+
+	#[tokio::test]
+	async fn ShapeRewritesBeforeSite() {
+		let Log = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+		let Sequence = SequenceWith(Fate(&[("End", 1)])).WithShape(Arc::new({
+			let Log = Log.clone();
+
+			move |_Action| Box::new(Tag("Shaped", Log.clone()))
+		}));
+
+		Sequence.Production.Assign(Box::new(Tag("Original", Log.clone()))).await;
+
+		Sequence.Production.Assign(Box::new(Broken)).await;
+
+		assert!(Sequence.Step().await.unwrap().is_ok());
+
+		assert!(Sequence.Step().await.unwrap().is_ok());
+
+		assert_eq!(*Log.lock().unwrap(), ["Shaped", "Shaped"]);
+	}
+
+	#[tokio::test]
+	async fn ExecuteProcessesLikeRun() {
+		let Log = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+		let (Grave, mut Buried) = unbounded_channel();
+
+		let Sequence = SequenceWith(Fate(&[("End", 1)])).WithGrave(Grave).WithShape(Arc::new({
+			let Log = Log.clone();
+
+			move |Action:Box<dyn Action>| {
+				Log.lock().unwrap().push("Shaped");
+
+				Action
+			}
+		}));
+
+		let Recorder = Tally::default();
+
+		let _Guard = set_default_local_recorder(&Recorder);
+
+		let Result = Sequence.Execute(vec![Box::new(Probe), Box::new(Broken)]).await;
+
+		assert_eq!(Result.iter().map(Result::is_ok).collect::<Vec<_>>(), [true, false]);
+
+		assert_eq!(*Log.lock().unwrap(), ["Shaped", "Shaped"]);
+
+		assert_eq!(Recorder.Get("Echo.Sequence.Processed"), 2);
+
+		assert_eq!(Recorder.Get("Echo.Sequence.Failed"), 1);
+
+		assert!(matches!(Buried.try_recv().unwrap().1, Error::Execution(_)));
+	}
+
+	#[tokio::test]
+	async fn RateSpreadsActions() {
+		let Sequence = SequenceWith(Fate(&[("Rate", 20)]));
+
+		let Start = std::time::Instant::now();
+
+		let Result =
+			Sequence.Execute((0..5).map(|_| Box::new(Probe) as Box<dyn Action>).collect()).await;
+
+		assert!(Result.iter().all(Result::is_ok));
+
+		// The first action goes at once and each other one 50 milliseconds later
+		assert!(Start.elapsed() >= Duration::from_millis(200));
+	}
+
+	#[tokio::test]
+	async fn LimitBoundsPool() {
+		let Sequence = Sequence().WithLimit(2);
+
+		let Busy = Busy::New();
+
+		for _ in 0..6 {
+			Sequence.Production.Assign(Box::new(Busy.clone())).await;
+		}
+
+		let Pool = Sequence.Spawn(6);
+
+		Sequence.Settle().await;
+
+		for Run in Pool {
+			Run.await.unwrap();
+		}
+
+		assert_eq!(Busy.Peak.load(Ordering::SeqCst), 2);
+	}
+
+	#[tokio::test]
+	async fn PauseHoldsActionsUntilResume() {
+		let Sequence = SequenceWith(Fate(&[("Interval", 60_000)]));
+
+		Sequence.Pause().await;
+
+		let Run = tokio::spawn({
+			let Sequence = Sequence.clone();
+
+			async move { Sequence.Run().await }
+		});
+
+		let (Sender, mut Receiver) = unbounded_channel();
+
+		for _ in 0..2 {
+			Sequence.Production.Assign(Box::new(Signal(Sender.clone()))).await;
+		}
+
+		assert!(tokio::time::timeout(Duration::from_millis(100), Receiver.recv()).await.is_err());
+
+		assert_eq!(Sequence.Production.Len().await, 2);
+
+		Sequence.Resume().await;
+
+		for _ in 0..2 {
+			tokio::time::timeout(Duration::from_secs(5), Receiver.recv())
+				.await
+				.expect("queued action was not taken after Resume");
+		}
+
+		Sequence.Shutdown().await;
+
+		assert_eq!(Run.await.unwrap().Processed, 2);
+	}
+
+	#[tokio::test]
+	async fn CostStaysWithinBudget() {
+		// The action costing more than the budget runs alone, holding all of it
+		for (Cost, Peak) in [(vec![2, 2, 2, 1, 1, 1], 3), (vec![5, 1, 1], 5)] {
+			let Sequence = Sequence().WithLimit(3);
+
+			let Busy = Busy::New();
+
+			for Cost in Cost {
+				Sequence.Production.Assign(Box::new(Busy.WithCost(Cost))).await;
+			}
+
+			let Pool = Sequence.Spawn(6);
+
+			Sequence.Settle().await;
+
+			for Run in Pool {
+				Run.await.unwrap();
+			}
+
+			assert_eq!(Busy.Peak.load(Ordering::SeqCst), Peak);
+		}
+	}
+
+	#[tokio::test]
+	async fn UnhealthySiteReceivesNothing() {
+		let Healthy = Arc::new(AtomicBool::new(false));
+
+		let Sequence = Struct {
+			Site:Arc::new(Mount(Healthy.clone())),
+			..SequenceWith(Fate(&[("Interval", 10)]))
+		};
+
+		let Run = tokio::spawn({
+			let Sequence = Sequence.clone();
+
+			async move { Sequence.Run().await }
+		});
+
+		let (Sender, mut Receiver) = unbounded_channel();
+
+		Sequence.Production.Assign(Box::new(Signal(Sender))).await;
+
+		assert!(tokio::time::timeout(Duration::from_millis(100), Receiver.recv()).await.is_err());
+
+		assert_eq!(Sequence.Production.Len().await, 1);
+
+		Healthy.store(true, Ordering::SeqCst);
+
+		tokio::time::timeout(Duration::from_secs(5), Receiver.recv())
+			.await
+			.expect("action was not taken once the site recovered");
+
+		Sequence.Shutdown().await;
+
+		Run.await.unwrap();
+	}
+
+	#[tokio::test]
+	async fn ClosedGraveCountsDroppedActions() {
+		let (Grave, Buried) = unbounded_channel();
+
+		drop(Buried);
+
+		let Sequence = SequenceWith(Fate(&[("End", 1)])).WithGrave(Grave);
+
+		for _ in 0..3 {
+			Sequence.Production.Assign(Box::new(Broken)).await;
+		}
+
+		let Recorder = Tally::default();
+
+		let _Guard = set_default_local_recorder(&Recorder);
+
+		assert!(Sequence.Step().await.unwrap().is_err());
+
+		assert_eq!(Recorder.Get("Echo.Sequence.Dropped"), 1);
+
+		assert_eq!(Sequence.Production.Len().await, 2);
+
+		while Sequence.Step().await.is_some() {}
+
+		assert_eq!(Recorder.Get("Echo.Sequence.Dropped"), 3);
+	}
+
+	#[tokio::test]
+	async fn RunSummarizesActions() {
+		let Sequence = SequenceWith(Fate(&[("End", 1)]));
+
+		for Action in [Box::new(Probe) as Box<dyn Action>, Box::new(Broken), Box::new(Probe)] {
+			Sequence.Production.Assign(Action).await;
+		}
+
+		let Pool = Sequence.Spawn(2);
+
+		Sequence.Settle().await;
+
+		let mut Summary = Vec::new();
+
+		for Run in Pool {
+			Summary.push(Run.await.unwrap());
+		}
+
+		assert_eq!(
+			super::Summary::Struct::Total(Summary),
+			super::Summary::Struct { Processed:3, Succeeded:2, Failed:1 }
+		);
+	}
+
+	#[tokio::test]
+	async fn StepDrainsQueue() {
+		let Sequence = SequenceWith(Fate(&[("End", 1)]));
+
+		assert!(Sequence.Step().await.is_none());
+
+		Sequence.Production.Assign(Box::new(Probe)).await;
+
+		Sequence.Production.Assign(Box::new(Broken)).await;
+
+		assert!(matches!(Sequence.Step().await, Some(Ok(()))));
+
+		assert!(matches!(Sequence.Step().await, Some(Err(Error::Execution(_)))));
+
+		assert!(Sequence.Step().await.is_none());
+
+		assert_eq!(Sequence.Production.Len().await, 0);
+	}
+
+	#[tokio::test]
+	async fn ConfiguredSequenceProcessesAction() {
+		let (Grave, mut Buried) = unbounded_channel();
+
+		let Sequence = SequenceWith(Fate(&[("Interval", 10), ("Timeout", 1000), ("End", 1)]))
+			.WithLimit(1)
+			.WithGrave(Grave);
+
+		let Pool = Sequence.Spawn(2);
+
+		let (Sender, mut Receiver) = unbounded_channel();
+
+		Sequence.Production.Assign(Box::new(Signal(Sender))).await;
+
+		tokio::time::timeout(Duration::from_secs(5), Receiver.recv())
+			.await
+			.expect("configured sequence did not process the action");
+
+		Sequence.Shutdown().await;
+
+		let mut Summary = Vec::new();
+
+		for Run in Pool {
+			Summary.push(Run.await.unwrap());
+		}
+
+		assert_eq!(super::Summary::Struct::Total(Summary).Succeeded, 1);
+
+		assert!(Buried.try_recv().is_err());
+	}
+
+	#[tokio::test]
+	async fn ReceiveTimesEachAttempt() {
+		let Sequence = SequenceWith(Fate(&[("End", 1)]));
+
+		Sequence.Production.Assign(Box::new(Sleep(Duration::from_millis(20)))).await;
+
+		let Recorder = Tally::default();
+
+		let _Guard = set_default_local_recorder(&Recorder);
+
+		Capture::Start();
+
+		Sequence.Step().await.unwrap().unwrap();
+
+		let Timed:Vec<_> = Capture::Take("Echo::Sequence")
+			.into_iter()
+			.filter_map(|(_, Message)| {
+				Message.strip_prefix("Action completed in ").map(str::to_string)
+			})
+			.collect();
+
+		assert_eq!(Timed.len(), 1);
+
+		assert!(Timed[0].ends_with("ms"), "{} is not milliseconds", Timed[0]);
+
+		assert!(Recorder.Get("Echo.Sequence.Busy") >= 20_000);
+	}
+
+	#[tokio::test]
+	async fn ProcessLogsStartAndOutcome() {
+		let Fate = config::Config::builder()
+			.set_override("End", 1)
+			.unwrap()
+			.set_override("Level", "info")
+			.unwrap()
+			.build()
+			.unwrap();
+
+		let Sequence = SequenceWith(Fate);
+
+		Sequence.Production.Assign(Box::new(Tag("Write", Arc::default()))).await;
+
+		Sequence.Production.Assign(Box::new(Broken)).await;
+
+		Capture::Start();
+
+		Sequence.Step().await.unwrap().unwrap();
+
+		assert!(Sequence.Step().await.unwrap().is_err());
+
+		let Record = Capture::Take("Echo::Sequence");
+
+		assert_eq!(Record.len(), 7);
+
+		let Expected = [
+			(log::Level::Info, "Action Write started"),
+			(log::Level::Debug, "Action completed in "),
+			(log::Level::Info, "Action Write succeeded in "),
+			(log::Level::Info, "Action unnamed started"),
+			(log::Level::Debug, "Action failed in "),
+			(log::Level::Warn, "Action unnamed failed on attempt 1 of 1, giving up"),
+			(log::Level::Error, "Action unnamed failed in "),
+		];
+
+		for ((Level, Message), (Wanted, Prefix)) in Record.iter().zip(Expected) {
+			assert_eq!(*Level, Wanted);
+
+			assert!(Message.starts_with(Prefix), "{} does not start with {}", Message, Prefix);
+		}
+	}
+}
@@ -7,6 +7,14 @@ pub struct Struct<T:Send + Sync> {
 	pub Content:T,
 	pub License:Signal<bool>,
 	pub Plan:Arc<Formality>,
+
+	/// Set once the action is cancelled. Clones share the signal, so a clone
+	/// kept by the caller can cancel a copy that is already queued.
+	pub Abort:Signal<bool>,
+
+	/// Wakes the function of every clone as soon as `Cancel` is called, so a
+	/// running function is abandoned instead of finishing.
+	pub Halt:Arc<Notify>,
 }
 
 impl<T:Send + Sync + Serialize> Serialize for Struct<T> {
@@ -44,7 +52,58 @@ impl<T:Send + Sync + Serialize + for<'de> Deserialize<'de>> Struct<T> {
 
 		Metadata.Insert("License".to_string(), serde_json::json!("valid"));
 
-		Struct { Metadata, Content, License:Signal::New(true), Plan }
+		Struct {
+			Metadata,
+			Content,
+			License:Signal::New(true),
+			Plan,
+			Abort:Signal::New(false),
+			Halt:Arc::new(Notify::new()),
+		}
+	}
+
+	/// Creates a new `Struct` instance from its textual form.
+	///
+	/// The text is `Name` or `Name:Content`. `Content` is read as JSON and
+	/// falls back to a plain string when it is not valid JSON, so
+	/// `Read:/tmp/file` and `Write:["/tmp/file", "Text"]` are both accepted.
+	/// Without `Content`, the content is read from `null`. Since the action
+	/// needs a plan, this stands in for `FromStr`.
+	///
+	/// # Arguments
+	///
+	/// * `Text` - The textual form of the action.
+	/// * `Plan` - The plan for executing the action.
+	///
+	/// # Returns
+	///
+	/// A Result containing the new `Struct` instance.
+	///
+	/// # Errors
+	///
+	/// Returns an `Argument` error if the name is empty or contains whitespace,
+	/// or if the content cannot be read as a `T`.
+	pub fn Parse(Text:&str, Plan:Arc<Formality>) -> Result<Self, Error> {
+		let (Name, Content) = match Text.trim().split_once(':') {
+			Some((Name, Content)) => {
+				(
+					Name,
+					serde_json::from_str(Content)
+						.unwrap_or_else(|_| serde_json::Value::String(Content.to_string())),
+				)
+			},
+			None => (Text.trim(), serde_json::Value::Null),
+		};
+
+		if Name.is_empty() || Name.contains(char::is_whitespace) {
+			return Err(Error::Argument(format!("Invalid action name in: {}", Text)));
+		}
+
+		let Content = serde_json::from_value(Content).map_err(|_Error| {
+			Error::Argument(format!("Invalid content for action {}: {}", Name, _Error))
+		})?;
+
+		Ok(Self::New(Name, Content, Plan))
 	}
 
 	/// Adds metadata to the action.
@@ -65,6 +124,14 @@ impl<T:Send + Sync + Serialize + for<'de> Deserialize<'de>> Struct<T> {
 
 	/// Executes the action.
 	///
+	/// An action whose `Key` metadata is a string is idempotent: its function
+	/// runs at most once per key within a context, and later executions with
+	/// the same key succeed without running it, as described in `Claim`.
+	///
+	/// An action whose `Deadline` metadata is a number, in milliseconds since
+	/// the Unix epoch, fails with an `Expired` error if it has passed, and its
+	/// function is abandoned if it is still running when the deadline comes.
+	///
 	/// # Arguments
 	///
 	/// * `Context` - The context in which to execute the action.
@@ -86,11 +153,53 @@ impl<T:Send + Sync + Serialize + for<'de> Deserialize<'de>> Struct<T> {
 
 		self.License().await?;
 
+		self.Abort().await?;
+
+		self.Remaining().await?;
+
 		self.Delay().await?;
 
 		self.Hooks(Context).await?;
 
-		self.Function(&Action).await?;
+		self.Abort().await?;
+
+		let Key = self.Metadata.Get("Key").await.and_then(|Key| Key.as_str().map(str::to_string));
+
+		let Hold = match &Key {
+			Some(Key) => {
+				let Some(Hold) = Self::Claim(Key, Context).await else {
+					info!("Skipping action {}, key {} was already applied", Action, Key);
+
+					return Ok(());
+				};
+
+				Some(Hold)
+			},
+			None => None,
+		};
+
+		let Result = match self.Remaining().await {
+			Ok(Some(Remaining)) => {
+				tokio::time::timeout(Remaining, self.Abortable(&Action)).await.unwrap_or_else(|_| {
+					Err(Error::Expired(format!("Action {} passed its deadline", Action)))
+				})
+			},
+			Ok(None) => self.Abortable(&Action).await,
+			Err(_Error) => Err(_Error),
+		};
+
+		match (Result, Hold) {
+			(Ok(()), Some(Hold)) => Hold.Keep(),
+			(Ok(()), None) => {},
+			(Err(_Error), Hold) => {
+				// Releases the key, so a retry can still apply the action
+				if let Some(Hold) = Hold {
+					Hold.Release().await;
+				}
+
+				return Err(_Error);
+			},
+		}
 
 		self.Next(Context).await?;
 
@@ -106,6 +215,120 @@ impl<T:Send + Sync + Serialize + for<'de> Deserialize<'de>> Struct<T> {
 		Ok(())
 	}
 
+	/// Cancels the action and every clone of it.
+	///
+	/// A cancelled action stops with a `Cancellation` error: before its
+	/// function if it has not started it yet, or by dropping the function
+	/// future if it is running, so the function stops at its next `.await`.
+	pub async fn Cancel(&self) {
+		self.Abort.Set(true).await;
+
+		self.Halt.notify_waiters();
+	}
+
+	/// Checks if the action has been cancelled.
+	async fn Abort(&self) -> Result<(), Error> {
+		if self.Abort.Get().await {
+			return Err(Error::Cancellation("Action was cancelled".to_string()));
+		}
+
+		Ok(())
+	}
+
+	/// Waits until the action is cancelled.
+	async fn Aborted(&self) {
+		loop {
+			// Register interest before checking, so a cancellation in between
+			// still wakes this waiter
+			let Halt = self.Halt.notified();
+
+			pin!(Halt);
+
+			Halt.as_mut().enable();
+
+			if self.Abort.Get().await {
+				return;
+			}
+
+			Halt.await;
+		}
+	}
+
+	/// Executes the function associated with the action, unless it is
+	/// cancelled first.
+	async fn Abortable(&self, Action:&str) -> Result<(), Error> {
+		select! {
+			Result = self.Function(Action) => Result,
+			_ = self.Aborted() => {
+				Err(Error::Cancellation(format!("Action {} was cancelled while running", Action)))
+			},
+		}
+	}
+
+	/// Computes the time left until the deadline in the metadata.
+	///
+	/// # Returns
+	///
+	/// The time left, or `None` if the action has no deadline.
+	///
+	/// # Errors
+	///
+	/// Returns an `Expired` error if the deadline has already passed.
+	async fn Remaining(&self) -> Result<Option<Duration>, Error> {
+		let Some(Deadline) = self.Metadata.Get("Deadline").await.and_then(|Value| Value.as_u64())
+		else {
+			return Ok(None);
+		};
+
+		let Deadline = UNIX_EPOCH + Duration::from_millis(Deadline);
+
+		match Deadline.duration_since(SystemTime::now()) {
+			Ok(Remaining) if !Remaining.is_zero() => Ok(Some(Remaining)),
+			_ => Err(Error::Expired("Action passed its deadline".to_string())),
+		}
+	}
+
+	/// Claims an idempotency key in the context cache.
+	///
+	/// Keys are stored in `Context.Cache` as `Key:<key>`, with the time they
+	/// were claimed. They are kept for `Remember` milliseconds (`Remember` in
+	/// `Life.Fate`, one hour by default), and expired keys are evicted on
+	/// every claim, which bounds the store by the number of keys claimed in
+	/// that window. A key claimed by an action still running counts as
+	/// applied, so concurrent duplicates run once as well. The key is released
+	/// again if the action fails, or if it is abandoned before its function
+	/// finished, for instance by a timeout dropping it.
+	///
+	/// # Returns
+	///
+	/// The `Hold` on the key if it was free and is now claimed, or `None` if
+	/// it was already applied.
+	async fn Claim(Key:&str, Context:&Life) -> Option<Hold> {
+		let Remember = Context.Fate.get_int("Remember").unwrap_or(3_600_000) as u64;
+
+		let Now = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map(|Now| Now.as_millis() as u64)
+			.unwrap_or(0);
+
+		let Cache = Context.Cache.lock().await;
+
+		Cache.retain(|Name, Claimed| {
+			!Name.starts_with("Key:")
+				|| Claimed.as_u64().is_some_and(|Claimed| Now.saturating_sub(Claimed) < Remember)
+		});
+
+		let Name = format!("Key:{}", Key);
+
+		if Cache.contains_key(&Name) {
+			return None;
+		}
+
+		Cache.insert(Name.clone(), serde_json::json!(Now));
+
+		Some(Hold { Cache:Context.Cache.clone(), Name:Some(Name) })
+	}
+
 	/// Applies any delay specified in the metadata.
 	async fn Delay(&self) -> Result<(), Error> {
 		if let Some(Delay) = self.Metadata.Get("Delay").await {
@@ -129,12 +352,11 @@ impl<T:Send + Sync + Serialize + for<'de> Deserialize<'de>> Struct<T> {
 	}
 
 	/// Executes the function associated with the action.
+	///
+	/// The function stays registered in the plan, so the same action type can
+	/// be executed any number of times.
 	async fn Function(&self, Action:&str) -> Result<(), Error> {
-		if let Some(Function) = self.Plan.Remove(Action) {
-			self.Result(Function.call((self.Argument().await?,)).await?).await?;
-		} else {
-			return Err(Error::Execution(format!("No function found for action type: {}", Action)));
-		}
+		self.Result(self.Plan.Call(Action, self.Argument().await?).await?).await?;
 
 		Ok(())
 	}
@@ -146,28 +368,98 @@ impl<T:Send + Sync + Serialize + for<'de> Deserialize<'de>> Struct<T> {
 				Error::Execution(format!("Failed to parse NextAction: {}", _Error))
 			})?;
 
-			Next.Execute(Context).await?;
+			// Boxed, since the next action's `Execute` calls this again
+			Box::pin(Next.Execute(Context)).await?;
 		}
 
 		Ok(())
 	}
 
-	/// Retrieves the arguments for the action.
-	async fn Argument(&self) -> Result<Vec<serde_json::Value>, Error> { Ok(vec![]) }
+	/// Retrieves the arguments for the action from its content.
+	///
+	/// Content that serializes to an array gives one argument per element,
+	/// `null` gives none, and any other value is the only argument, matching
+	/// the textual forms accepted by `Parse`.
+	async fn Argument(&self) -> Result<Vec<serde_json::Value>, Error> {
+		let Content = serde_json::to_value(&self.Content)
+			.map_err(|_Error| Error::Argument(format!("Invalid content: {}", _Error)))?;
+
+		Ok(match Content {
+			serde_json::Value::Array(Argument) => Argument,
+			serde_json::Value::Null => Vec::new(),
+			Argument => vec![Argument],
+		})
+	}
 
 	/// Processes the result of the action.
 	async fn Result(&self, _Result:serde_json::Value) -> Result<(), Error> { Ok(()) }
 }
 
-use std::{fmt::Debug, sync::Arc};
+/// An idempotency key claimed by a running action.
+///
+/// Unless the action applied it and `Keep` was called, the key is released
+/// when the hold is dropped, so an action abandoned mid-function can still be
+/// applied by a retry.
+struct Hold {
+	/// The context cache holding the key.
+	Cache:Arc<Mutex<DashMap<String, serde_json::Value>>>,
+
+	/// The name of the key in the cache, or `None` once it is settled.
+	Name:Option<String>,
+}
+
+impl Hold {
+	/// Keeps the key, as the action was applied.
+	fn Keep(mut self) { self.Name = None; }
+
+	/// Releases the key, waiting for the lock on the cache.
+	async fn Release(mut self) {
+		if let Some(Name) = self.Name.take() {
+			self.Cache.lock().await.remove(&Name);
+		}
+	}
+}
+
+impl Drop for Hold {
+	fn drop(&mut self) {
+		let Some(Name) = self.Name.take() else {
+			return;
+		};
+
+		// The action was abandoned, so the lock cannot be awaited here
+		match self.Cache.try_lock() {
+			Ok(Cache) => {
+				Cache.remove(&Name);
+			},
+			Err(_) => {
+				if let Ok(Handle) = tokio::runtime::Handle::try_current() {
+					let Cache = self.Cache.clone();
+
+					Handle.spawn(async move {
+						Cache.lock().await.remove(&Name);
+					});
+				}
+			},
+		}
+	}
+}
+
+use std::{
+	fmt::Debug,
+	sync::Arc,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
+use dashmap::DashMap;
 use log::info;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tokio::{pin, select, sync::Notify};
 
 use crate::{
 	Enum::Sequence::Action::Error::Enum as Error,
 	Struct::Sequence::{
 		Life::Struct as Life,
+		Mutex,
 		Plan::Formality::Struct as Formality,
 		Signal::Struct as Signal,
 		Vector::Struct as Vector,
@@ -175,3 +467,245 @@ use crate::{
 };
 
 pub mod Signature;
+
+#[cfg(test)]
+mod Test {
+	use std::{
+		sync::atomic::{AtomicUsize, Ordering},
+		time::{Duration, SystemTime, UNIX_EPOCH},
+	};
+
+	use serde_json::{Value, json};
+
+	use super::Struct;
+	use crate::{
+		Enum::Sequence::Action::Error::Enum as Error,
+		Struct::Sequence::{
+			Action::Signature::Struct as Signature,
+			Arc,
+			Fixture::{self, Context},
+			Plan::{Formality::Struct as Formality, Struct as Plan},
+			Production,
+			Site::Struct as Site,
+			Struct as Sequence,
+		},
+		Trait::Sequence::Site::Trait as _,
+	};
+
+	/// A plan whose `Count` function counts its calls on the returned counter.
+	fn Counting() -> (Arc<AtomicUsize>, Arc<Formality>) {
+		let Count = Arc::new(AtomicUsize::new(0));
+
+		let Counted = Count.clone();
+
+		let Plan = Plan::New()
+			.WithSignature(Signature::New("Count"))
+			.WithFunction("Count", move |_| {
+				let Counted = Counted.clone();
+
+				async move {
+					Counted.fetch_add(1, Ordering::SeqCst);
+
+					Ok(Value::Null)
+				}
+			})
+			.unwrap()
+			.Build();
+
+		(Count, Arc::new(Plan))
+	}
+
+	#[tokio::test]
+	async fn ReceiveRunsFunction() {
+		let (Count, Plan) = Counting();
+
+		let Action = Struct::New("Count", json!(null), Plan);
+
+		Site.Receive(Box::new(Action), &Context()).await.unwrap();
+
+		assert_eq!(Count.load(Ordering::SeqCst), 1);
+	}
+
+	#[tokio::test]
+	async fn CancelAbortsInFlight() {
+		let Plan = Plan::New()
+			.WithSignature(Signature::New("Hang"))
+			.WithFunction("Hang", |_| {
+				async {
+					tokio::time::sleep(Duration::from_secs(3600)).await;
+
+					Ok(Value::Null)
+				}
+			})
+			.unwrap()
+			.Build();
+
+		let Action = Struct::New("Hang", json!(null), Arc::new(Plan));
+
+		let Handle = Action.clone();
+
+		tokio::spawn(async move {
+			tokio::time::sleep(Duration::from_millis(50)).await;
+
+			Handle.Cancel().await;
+		});
+
+		let Result = tokio::time::timeout(
+			Duration::from_secs(5),
+			Site.Receive(Box::new(Action), &Context()),
+		)
+		.await
+		.expect("cancelled action kept running");
+
+		assert!(matches!(Result, Err(Error::Cancellation(_))));
+	}
+
+	#[tokio::test]
+	async fn ParseReadsTextualForms() {
+		let Plan = Arc::new(Plan::New().Build());
+
+		for (Text, Name, Content) in [
+			("Read:/tmp/file", "Read", json!("/tmp/file")),
+			(r#"Write:["/tmp/file", "Text"]"#, "Write", json!(["/tmp/file", "Text"])),
+			("Wait:5", "Wait", json!(5)),
+			("  Ping  ", "Ping", json!(null)),
+		] {
+			let Action = Struct::<Value>::Parse(Text, Plan.clone()).unwrap();
+
+			assert_eq!(Action.Metadata.Get("Action").await, Some(json!(Name)));
+
+			assert_eq!(Action.Content, Content);
+		}
+
+		for Text in ["", ":/tmp/file", "Two words:1"] {
+			assert!(matches!(Struct::<Value>::Parse(Text, Plan.clone()), Err(Error::Argument(_))));
+		}
+
+		assert!(matches!(
+			Struct::<Vec<String>>::Parse("Write:5", Plan.clone()),
+			Err(Error::Argument(_))
+		));
+	}
+
+	#[tokio::test]
+	async fn KeyAppliesOnce() {
+		let (Count, Plan) = Counting();
+
+		let Shared = Context();
+
+		for Key in ["First", "First", "Second", "First"] {
+			let Action =
+				Struct::New("Count", json!(null), Plan.clone()).WithMetadata("Key", json!(Key));
+
+			Action.Execute(&Shared).await.unwrap();
+		}
+
+		assert_eq!(Count.load(Ordering::SeqCst), 2);
+
+		// Keys are remembered per context
+		Struct::New("Count", json!(null), Plan.clone())
+			.WithMetadata("Key", json!("First"))
+			.Execute(&Context())
+			.await
+			.unwrap();
+
+		assert_eq!(Count.load(Ordering::SeqCst), 3);
+	}
+
+	#[tokio::test]
+	async fn KeyIsReleasedWhenTimedOut() {
+		let Count = Arc::new(AtomicUsize::new(0));
+
+		let Counted = Count.clone();
+
+		// Hangs on the first call and returns at once on the next
+		let Plan = Plan::New()
+			.WithSignature(Signature::New("Slow"))
+			.WithFunction("Slow", move |_| {
+				let First = Counted.fetch_add(1, Ordering::SeqCst) == 0;
+
+				async move {
+					if First {
+						tokio::time::sleep(Duration::from_secs(3600)).await;
+					}
+
+					Ok(Value::Null)
+				}
+			})
+			.unwrap()
+			.Build();
+
+		let Fate = config::Config::builder()
+			.set_override("Timeout", 50)
+			.unwrap()
+			.set_override("End", 2)
+			.unwrap()
+			.set_override("Ceiling", 0)
+			.unwrap()
+			.build()
+			.unwrap();
+
+		let Sequence = Sequence::New(
+			Arc::new(Site),
+			Arc::new(Production::Struct::New()),
+			Fixture::Life(Fate),
+		);
+
+		let Action =
+			Struct::New("Slow", json!(null), Arc::new(Plan)).WithMetadata("Key", json!("Once"));
+
+		assert!(Sequence.Execute(vec![Box::new(Action.clone())]).await[0].is_ok());
+
+		assert_eq!(Count.load(Ordering::SeqCst), 2);
+
+		// The retry applied the key, so it is not run again
+		assert!(Sequence.Execute(vec![Box::new(Action)]).await[0].is_ok());
+
+		assert_eq!(Count.load(Ordering::SeqCst), 2);
+	}
+
+	#[tokio::test]
+	async fn DeadlineExpiresOrLeavesTime() {
+		let (Count, Plan) = Counting();
+
+		let Now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+
+		let Action = |Name:&str, Plan:&Arc<Formality>, Deadline:u64| {
+			Struct::New(Name, json!(null), Plan.clone()).WithMetadata("Deadline", json!(Deadline))
+		};
+
+		assert!(matches!(
+			Action("Count", &Plan, Now - 1000).Execute(&Context()).await,
+			Err(Error::Expired(_))
+		));
+
+		assert_eq!(Count.load(Ordering::SeqCst), 0);
+
+		Action("Count", &Plan, Now + 60_000).Execute(&Context()).await.unwrap();
+
+		assert_eq!(Count.load(Ordering::SeqCst), 1);
+
+		let Plan = Arc::new(
+			Plan::New()
+				.WithSignature(Signature::New("Hang"))
+				.WithFunction("Hang", |_| {
+					async {
+						tokio::time::sleep(Duration::from_secs(3600)).await;
+
+						Ok(Value::Null)
+					}
+				})
+				.unwrap()
+				.Build(),
+		);
+
+		let Result = tokio::time::timeout(
+			Duration::from_secs(5),
+			Action("Hang", &Plan, Now + 200).Execute(&Context()),
+		)
+		.await
+		.expect("action kept running past its deadline");
+
+		assert!(matches!(Result, Err(Error::Expired(_))));
+	}
+}
@@ -2,7 +2,7 @@
 ///
 /// This struct encapsulates the name of an action signature, which can be used
 /// for identifying and describing different types of actions within a system.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Struct {
 	/// The name of the action signature.
 	///
@@ -10,4 +10,180 @@ pub struct Struct {
 	/// action. It can be used to look up or reference specific actions within
 	/// a larger system.
 	pub Name:String,
+
+	/// The parameters the action expects, in order, if they are declared.
+	///
+	/// When set, `Formality::Call` rejects calls whose arguments differ in
+	/// number or kind before invoking the function. `None` disables the check.
+	pub Parameter:Option<Vec<Parameter::Struct>>,
+
+	/// The kind of any arguments accepted after the declared parameters, if
+	/// the action is variadic.
+	///
+	/// When set, `Formality::Call` accepts any number of trailing arguments of
+	/// this kind, including none, so `Parameter` becomes a minimum.
+	pub Variadic:Option<Kind>,
+
+	/// The kind of value the action returns, if it is declared.
+	///
+	/// When set, `Formality::Call` rejects a result of any other kind.
+	pub Return:Option<Kind>,
+}
+
+impl Struct {
+	/// Creates a new `Struct` instance without declared parameters, variadic
+	/// or return kind.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the action.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance whose calls are not validated.
+	pub fn New(Name:&str) -> Self {
+		Struct { Name:Name.to_string(), Parameter:None, Variadic:None, Return:None }
+	}
+
+	/// Declares the next parameter of the action.
+	///
+	/// Declaring any parameter turns on validation, so an action with no
+	/// parameters at all is declared with `Parameter:Some(Vec::new())`.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the parameter.
+	/// * `Kind` - The kind of JSON value the parameter accepts.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithParameter(mut self, Name:&str, Kind:Kind) -> Self {
+		self.Parameter
+			.get_or_insert_with(Vec::new)
+			.push(Parameter::Struct { Name:Name.to_string(), Kind, Optional:false });
+
+		self
+	}
+
+	/// Declares the next parameter of the action as optional, so calls may
+	/// leave its argument out.
+	///
+	/// Once a parameter is optional, every parameter declared after it is
+	/// read as optional as well, since only trailing arguments can be left
+	/// out.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the parameter.
+	/// * `Kind` - The kind of JSON value the parameter accepts when given.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithOptional(mut self, Name:&str, Kind:Kind) -> Self {
+		self.Parameter
+			.get_or_insert_with(Vec::new)
+			.push(Parameter::Struct { Name:Name.to_string(), Kind, Optional:true });
+
+		self
+	}
+
+	/// Declares that the action accepts any number of trailing arguments of
+	/// one kind after its declared parameters.
+	///
+	/// This turns on validation like `WithParameter`, so an action taking only
+	/// variadic arguments needs no declared parameter.
+	///
+	/// # Arguments
+	///
+	/// * `Kind` - The kind of JSON value each trailing argument must be.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithVariadic(mut self, Kind:Kind) -> Self {
+		self.Parameter.get_or_insert_with(Vec::new);
+
+		self.Variadic = Some(Kind);
+
+		self
+	}
+
+	/// Declares the kind of value the action returns.
+	///
+	/// # Arguments
+	///
+	/// * `Kind` - The kind of JSON value the action returns.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithReturn(mut self, Kind:Kind) -> Self {
+		self.Return = Some(Kind);
+
+		self
+	}
+
+	/// Counts the arguments every call has to give, which are those of the
+	/// parameters declared before the first optional one.
+	///
+	/// # Returns
+	///
+	/// The number of required arguments, or `0` without declared parameters.
+	pub fn Required(&self) -> usize {
+		self.Parameter.as_ref().map_or(0, |Parameter| {
+			Parameter.iter().position(|Parameter| Parameter.Optional).unwrap_or(Parameter.len())
+		})
+	}
+}
+
+use serde::{Deserialize, Serialize};
+
+use crate::Enum::Sequence::Action::Signature::Kind::Enum as Kind;
+
+pub mod Parameter;
+
+#[cfg(test)]
+mod Test {
+	use serde_json::{Value, json};
+
+	use super::Struct;
+	use crate::{
+		Enum::Sequence::Action::{Error::Enum as Error, Signature::Kind::Enum as Kind},
+		Struct::Sequence::Plan::Formality::Struct as Formality,
+	};
+
+	#[tokio::test]
+	async fn BuildsAndSigns() {
+		let Signature = Struct::New("Add")
+			.WithParameter("Left", Kind::Number)
+			.WithParameter("Right", Kind::Number)
+			.WithReturn(Kind::Number);
+
+		let Name:Vec<&str> =
+			Signature.Parameter.iter().flatten().map(|Parameter| Parameter.Name.as_str()).collect();
+
+		assert_eq!(Name, ["Left", "Right"]);
+
+		assert_eq!(Signature.Return, Some(Kind::Number));
+
+		assert_eq!(Signature.Required(), 2);
+
+		let mut Formality = Formality::New();
+
+		Formality
+			.Sign(Signature)
+			.Add("Add", |Argument:Vec<Value>| async move {
+				Ok(json!(Argument[0].as_f64().unwrap() + Argument[1].as_f64().unwrap()))
+			})
+			.unwrap();
+
+		assert_eq!(Formality.Call("Add", vec![json!(1), json!(2)]).await.unwrap(), json!(3.0));
+
+		assert!(matches!(
+			Formality.Call("Add", vec![json!(1), json!("2")]).await,
+			Err(Error::Argument(_))
+		));
+	}
 }
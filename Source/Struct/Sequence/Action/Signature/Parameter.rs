@@ -0,0 +1,18 @@
+/// Describes one parameter of an action signature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The name of the parameter, used in argument error messages.
+	pub Name:String,
+
+	/// The kind of JSON value the parameter accepts.
+	pub Kind:Kind,
+
+	/// Whether the argument may be left out. Optional parameters follow every
+	/// required one, so only trailing arguments can be left out.
+	#[serde(default)]
+	pub Optional:bool,
+}
+
+use serde::{Deserialize, Serialize};
+
+use crate::Enum::Sequence::Action::Signature::Kind::Enum as Kind;
@@ -0,0 +1,56 @@
+/// Creates a context with the default configuration and nothing else in it.
+///
+/// # Returns
+///
+/// A new `Life` with empty maps and an empty `Fate`.
+pub fn Context() -> Life { Life(Config::default()) }
+
+/// Creates a context with the given configuration and nothing else in it.
+///
+/// # Arguments
+///
+/// * `Fate` - The configuration of the context.
+///
+/// # Returns
+///
+/// A new `Life` with empty maps.
+pub fn Life(Fate:Config) -> Life {
+	Life {
+		Span:Arc::new(DashMap::new()),
+		Fate:Arc::new(Fate),
+		Cache:Arc::new(Mutex::new(DashMap::new())),
+		Karma:Arc::new(DashMap::new()),
+	}
+}
+
+/// An action that always succeeds.
+pub struct Probe;
+
+#[async_trait]
+impl Action for Probe {
+	async fn Execute(&self, _Context:&Life) -> Result<(), Error> { Ok(()) }
+
+	fn Clone(&self) -> Box<dyn Action> { Box::new(Probe) }
+}
+
+/// An action that always succeeds, told apart by its name.
+pub struct Named(pub &'static str);
+
+#[async_trait]
+impl Action for Named {
+	async fn Execute(&self, _Context:&Life) -> Result<(), Error> { Ok(()) }
+
+	fn Clone(&self) -> Box<dyn Action> { Box::new(Named(self.0)) }
+
+	async fn Name(&self) -> Option<String> { Some(self.0.to_string()) }
+}
+
+use async_trait::async_trait;
+use config::Config;
+use dashmap::DashMap;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{Arc, Life::Struct as Life, Mutex},
+	Trait::Sequence::Action::Trait as Action,
+};
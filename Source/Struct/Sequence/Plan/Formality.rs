@@ -11,14 +11,7 @@ pub struct Struct {
 	/// These functions take a vector of JSON values as input and return a
 	/// pinned future that resolves to a Result containing either a JSON value
 	/// or an Error.
-	Function: DashMap<
-		String,
-		Box<
-			dyn Fn(Vec<Value>) -> Pin<Box<dyn Future<Output = Result<Value, Error>> + Send>>
-				+ Send
-				+ Sync,
-		>,
-	>,
+	Function:DashMap<String, crate::Type::Sequence::Action::Function::Type>,
 }
 
 impl Struct {
@@ -79,25 +72,393 @@ impl Struct {
 		Ok(self)
 	}
 
-	/// Removes and returns a function from the Function DashMap.
+	/// Checks whether an action can be called through the plan.
 	///
 	/// # Arguments
 	///
-	/// * `Name` - The name of the function to remove.
+	/// * `Name` - The name of the action.
+	///
+	/// # Returns
+	///
+	/// `true` if both a signature and a function are registered for `Name`.
+	pub fn Contains(&self, Name:&str) -> bool {
+		self.Signature.contains_key(Name) && self.Function.contains_key(Name)
+	}
+
+	/// Lists the signatures registered in the plan.
+	///
+	/// Signatures serialize, so the list can be persisted and checked against
+	/// the plan later with `Verify`.
 	///
 	/// # Returns
 	///
-	/// An Option containing a reference to the removed function, if it exists.
-	pub fn Remove(
+	/// A clone of every registered signature, sorted by name.
+	pub fn Signatures(&self) -> Vec<Signature> {
+		let mut Signatures:Vec<Signature> =
+			self.Signature.iter().map(|Entry| Entry.value().clone()).collect();
+
+		Signatures.sort_by(|Left, Right| Left.Name.cmp(&Right.Name));
+
+		Signatures
+	}
+
+	/// Checks that the plan registers exactly the expected signatures.
+	///
+	/// Together with `Signatures`, whose result can be persisted, this catches
+	/// an accidental change to the actions a plan offers, for instance across
+	/// a restart. Only signatures are compared, since functions cannot be.
+	///
+	/// # Arguments
+	///
+	/// * `Expected` - The signatures the plan should register.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if every expected signature is registered unchanged and no
+	/// other is.
+	///
+	/// # Errors
+	///
+	/// Returns a `Mismatch` listing the missing, changed and unexpected
+	/// actions.
+	pub fn Verify(&self, Expected:&[Signature]) -> Result<(), Mismatch::Struct> {
+		let mut Mismatch = Mismatch::Struct::default();
+
+		for Expected in Expected {
+			match self.Signature.get(&Expected.Name) {
+				None => Mismatch.Missing.push(Expected.Name.clone()),
+				Some(Registered) if *Registered != *Expected => {
+					Mismatch.Changed.push(Expected.Name.clone())
+				},
+				Some(_) => {},
+			}
+		}
+
+		for Entry in self.Signature.iter() {
+			if !Expected.iter().any(|Expected| Expected.Name == *Entry.key()) {
+				Mismatch.Unexpected.push(Entry.key().clone());
+			}
+		}
+
+		Mismatch.Missing.sort();
+
+		Mismatch.Changed.sort();
+
+		Mismatch.Unexpected.sort();
+
+		if Mismatch.IsEmpty() { Ok(()) } else { Err(Mismatch) }
+	}
+
+	/// Describes the calls the plan accepts as a JSON Schema.
+	///
+	/// The schema matches an object with the `Action` name and its `Argument`
+	/// array, with one alternative per registered signature. Declared
+	/// parameters fix the length and kind of each argument, optional ones
+	/// lower the minimum length, and a variadic kind lets the array grow with
+	/// trailing arguments of that kind.
+	///
+	/// # Returns
+	///
+	/// A JSON Schema (draft 2020-12) as a `Value`.
+	pub fn Schema(&self) -> Value {
+		let Alternative:Vec<Value> = self
+			.Signatures()
+			.into_iter()
+			.map(|Signature| {
+				let Argument = match &Signature.Parameter {
+					Some(Parameter) => {
+						let mut Argument = json!({
+							"type": "array",
+							"prefixItems": Parameter
+								.iter()
+								.map(|Parameter| Parameter.Kind.Schema())
+								.collect::<Vec<_>>(),
+							"minItems": Signature.Required(),
+						});
+
+						match Signature.Variadic {
+							Some(Variadic) => Argument["items"] = Variadic.Schema(),
+							None => Argument["maxItems"] = json!(Parameter.len()),
+						}
+
+						Argument
+					},
+					None => json!({ "type": "array" }),
+				};
+
+				json!({
+					"type": "object",
+					"properties": {
+						"Action": { "const": Signature.Name },
+						"Argument": Argument,
+					},
+					"required": ["Action", "Argument"],
+				})
+			})
+			.collect();
+
+		json!({
+			"$schema": "https://json-schema.org/draft/2020-12/schema",
+			"oneOf": Alternative,
+		})
+	}
+
+	/// Invokes a registered function by name.
+	///
+	/// The arguments are first checked against the signature registered for
+	/// `Name`. The function is looked up and called while the map entry is
+	/// held, but the returned future is awaited after the entry is released.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the function to call.
+	/// * `Argument` - The arguments to pass to the function.
+	///
+	/// # Returns
+	///
+	/// A Result containing the value returned by the function.
+	///
+	/// # Errors
+	///
+	/// Returns an `Execution` error if no function is registered under `Name`
+	/// or if it returns a value of another kind than its signature declares,
+	/// an `Argument` error if the arguments do not match its signature, or
+	/// whatever error the function itself returns.
+	pub async fn Call(&self, Name:&str, Argument:Vec<Value>) -> Result<Value, Error> {
+		self.Check(Name, &Argument)?;
+
+		let Return = self.Signature.get(Name).and_then(|Signature| Signature.Return);
+
+		let Future = match self.Function.get(Name) {
+			Some(Function) => Function.value()(Argument),
+			None => {
+				return Err(Error::Execution(format!("No function found for action: {}", Name)));
+			},
+		};
+
+		let Result = Future.await?;
+
+		if let Some(Return) = Return
+			&& !Return.Matches(&Result)
+		{
+			return Err(Error::Execution(format!(
+				"{} returned {:?}, expected {:?}",
+				Name,
+				Kind::Of(&Result),
+				Return
+			)));
+		}
+
+		Ok(Result)
+	}
+
+	/// Invokes a registered function by name, giving up after `Limit`.
+	///
+	/// The call behaves like `Call`, but if the function has not completed in
+	/// time its future is dropped, which cancels it, since nothing is spawned.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the function to call.
+	/// * `Argument` - The arguments to pass to the function.
+	/// * `Limit` - The time the function is allowed to take.
+	///
+	/// # Returns
+	///
+	/// A Result containing the value returned by the function.
+	///
+	/// # Errors
+	///
+	/// Returns a `Timeout` error if the function did not complete in `Limit`,
+	/// or any error `Call` returns.
+	pub async fn Within(
 		&self,
 		Name:&str,
-	) -> Option<
-		Box<
-			dyn Fn(Vec<Value>) -> Pin<Box<dyn Future<Output = Result<Value, Error>> + Send>>
-				+ Send
-				+ Sync,
-		>,
-	> {
+		Argument:Vec<Value>,
+		Limit:Duration,
+	) -> Result<Value, Error> {
+		timeout(Limit, self.Call(Name, Argument))
+			.await
+			.map_err(|_| Error::Timeout(format!("{} exceeded {:?}", Name, Limit)))?
+	}
+
+	/// Serves a JSON-RPC 2.0 request against the registered functions.
+	///
+	/// The request `method` names the function and its `params`, which must be
+	/// an array if present, become the arguments. The call goes through `Call`,
+	/// so it is validated against the signature. A request without an `id` is
+	/// a notification: its function is called all the same, but no response
+	/// is produced, not even for an error. Malformed JSON and invalid requests
+	/// cannot be told apart from requests, so they are always answered, with a
+	/// `null` `id`.
+	///
+	/// # Arguments
+	///
+	/// * `Request` - The JSON-RPC request text.
+	///
+	/// # Returns
+	///
+	/// The JSON-RPC response text, holding either the function `result` or an
+	/// `error` object with one of the standard codes: `-32700` for malformed
+	/// JSON, `-32600` for an invalid request, `-32601` for an unknown method,
+	/// `-32602` for invalid parameters and `-32603` for a failed call. `None`
+	/// for a notification.
+	pub async fn Dispatch(&self, Request:&str) -> Option<String> {
+		let Request:Value = match serde_json::from_str(Request) {
+			Ok(Request) => Request,
+			Err(_Error) => {
+				return Self::Reply(Some(Value::Null), Err((-32700, _Error.to_string())));
+			},
+		};
+
+		let Id = Request.get("id").cloned();
+
+		let Method = match Request.get("method").and_then(Value::as_str) {
+			Some(Method) if Request.get("jsonrpc").and_then(Value::as_str) == Some("2.0") => Method,
+			_ => {
+				return Self::Reply(
+					Some(Id.unwrap_or(Value::Null)),
+					Err((-32600, "Invalid request".to_string())),
+				);
+			},
+		};
+
+		let Argument = match Request.get("params") {
+			None => Vec::new(),
+			Some(Value::Array(Argument)) => Argument.clone(),
+			Some(_) => {
+				return Self::Reply(Id, Err((-32602, "Parameters must be an array".to_string())));
+			},
+		};
+
+		if !self.Signature.contains_key(Method) || !self.Function.contains_key(Method) {
+			return Self::Reply(Id, Err((-32601, format!("Method not found: {}", Method))));
+		}
+
+		match self.Call(Method, Argument).await {
+			Ok(Result) => Self::Reply(Id, Ok(Result)),
+			Err(_Error @ Error::Argument(_)) => Self::Reply(Id, Err((-32602, _Error.to_string()))),
+			Err(_Error) => Self::Reply(Id, Err((-32603, _Error.to_string()))),
+		}
+	}
+
+	/// Formats a JSON-RPC 2.0 response for `Dispatch`, or `None` if there is
+	/// no `Id` to answer, as for a notification.
+	fn Reply(Id:Option<Value>, Outcome:Result<Value, (i64, String)>) -> Option<String> {
+		let Id = Id?;
+
+		Some(
+			match Outcome {
+				Ok(Result) => json!({ "jsonrpc": "2.0", "result": Result, "id": Id }),
+				Err((Code, Message)) => {
+					json!({
+						"jsonrpc": "2.0",
+						"error": { "code": Code, "message": Message },
+						"id": Id,
+					})
+				},
+			}
+			.to_string(),
+		)
+	}
+
+	/// Checks arguments against the signature registered for an action.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the action.
+	/// * `Argument` - The arguments about to be passed to its function.
+	///
+	/// # Errors
+	///
+	/// Returns an `Argument` error if the signature declares parameters and
+	/// the arguments differ from them in number or kind, leaving out optional
+	/// ones aside, or if a trailing argument of a variadic signature is not of
+	/// its variadic kind.
+	fn Check(&self, Name:&str, Argument:&[Value]) -> Result<(), Error> {
+		let Some(Signature) = self.Signature.get(Name) else {
+			return Ok(());
+		};
+
+		let Some(Parameter) = &Signature.Parameter else {
+			return Ok(());
+		};
+
+		let Required = Signature.Required();
+
+		// The most arguments accepted, or `None` for a variadic signature
+		let Most = if Signature.Variadic.is_none() { Some(Parameter.len()) } else { None };
+
+		if Most == Some(Required) && Argument.len() != Required {
+			return Err(Error::Argument(format!(
+				"{} expects {} argument(s), got {}",
+				Name,
+				Required,
+				Argument.len()
+			)));
+		}
+
+		if let Some(Most) = Most
+			&& Argument.len() > Most
+		{
+			return Err(Error::Argument(format!(
+				"{} expects {} to {} argument(s), got {}",
+				Name,
+				Required,
+				Most,
+				Argument.len()
+			)));
+		}
+
+		if Argument.len() < Required {
+			return Err(Error::Argument(format!(
+				"{} expects at least {} argument(s), got {}",
+				Name,
+				Required,
+				Argument.len()
+			)));
+		}
+
+		for (Parameter, Argument) in Parameter.iter().zip(Argument) {
+			if !Parameter.Kind.Matches(Argument) {
+				return Err(Error::Argument(format!(
+					"{} expects {} to be {:?}, got {:?}",
+					Name,
+					Parameter.Name,
+					Parameter.Kind,
+					Kind::Of(Argument)
+				)));
+			}
+		}
+
+		if let Some(Variadic) = Signature.Variadic {
+			for (Index, Argument) in Argument.iter().enumerate().skip(Parameter.len()) {
+				if !Variadic.Matches(Argument) {
+					return Err(Error::Argument(format!(
+						"{} expects argument {} to be {:?}, got {:?}",
+						Name,
+						Index,
+						Variadic,
+						Kind::Of(Argument)
+					)));
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Removes and returns a function from the Function DashMap.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the function to remove.
+	///
+	/// # Returns
+	///
+	/// An Option containing the removed function, if it exists. The function
+	/// is owned and can be passed to `Add` on another `Struct`.
+	pub fn Remove(&self, Name:&str) -> Option<crate::Type::Sequence::Action::Function::Type> {
 		self.Function.remove(Name).map(|(_, v)| v)
 	}
 }
@@ -110,13 +471,338 @@ impl Debug for Struct {
 	}
 }
 
-use std::{fmt::Debug, pin::Pin};
+use std::{fmt::Debug, pin::Pin, time::Duration};
 
 use dashmap::DashMap;
 use futures::Future;
-use serde_json::Value;
+use serde_json::{Value, json};
+use tokio::time::timeout;
 
 use crate::{
-	Enum::Sequence::Action::Error::Enum as Error,
+	Enum::Sequence::Action::{Error::Enum as Error, Signature::Kind::Enum as Kind},
 	Struct::Sequence::Action::Signature::Struct as Signature,
 };
+
+pub mod Mismatch;
+
+#[cfg(test)]
+mod Test {
+	use std::time::Duration;
+
+	use serde_json::{Value, json};
+	use tokio::time::sleep;
+
+	use super::{Mismatch, Struct};
+	use crate::{
+		Enum::Sequence::Action::{Error::Enum as Error, Signature::Kind::Enum as Kind},
+		Struct::Sequence::{Action::Signature::Struct as Signature, Plan::Struct as Plan},
+	};
+
+	fn Formality() -> Struct {
+		Plan::New()
+			.WithSignature(Signature::New("Echo").WithParameter("Text", Kind::String))
+			.WithFunction("Echo", |Argument:Vec<Value>| async move { Ok(Argument[0].clone()) })
+			.unwrap()
+			.WithSignature(Signature::New("Broken"))
+			.WithFunction("Broken", |_| async { Err(Error::Execution("Broken".to_string())) })
+			.unwrap()
+			.Build()
+	}
+
+	async fn Code(Formality:&Struct, Request:&str) -> Value {
+		let Response = Formality.Dispatch(Request).await.unwrap();
+
+		serde_json::from_str::<Value>(&Response).unwrap()["error"]["code"].clone()
+	}
+
+	#[tokio::test]
+	async fn DispatchReturnsResult() {
+		let Response = Formality()
+			.Dispatch(r#"{"jsonrpc":"2.0","method":"Echo","params":["Hello"],"id":1}"#)
+			.await
+			.unwrap();
+
+		assert_eq!(
+			serde_json::from_str::<Value>(&Response).unwrap(),
+			json!({ "jsonrpc": "2.0", "result": "Hello", "id": 1 })
+		);
+	}
+
+	#[tokio::test]
+	async fn DispatchReturnsErrorCodes() {
+		let Formality = Formality();
+
+		assert_eq!(Code(&Formality, "{").await, -32700);
+
+		assert_eq!(Code(&Formality, r#"{"method":"Echo","id":1}"#).await, -32600);
+
+		assert_eq!(
+			Code(&Formality, r#"{"jsonrpc":"2.0","method":"Missing","id":1}"#).await,
+			-32601
+		);
+
+		assert_eq!(
+			Code(&Formality, r#"{"jsonrpc":"2.0","method":"Echo","params":[1],"id":1}"#).await,
+			-32602
+		);
+
+		assert_eq!(
+			Code(&Formality, r#"{"jsonrpc":"2.0","method":"Echo","params":"Hello","id":1}"#).await,
+			-32602
+		);
+
+		assert_eq!(
+			Code(&Formality, r#"{"jsonrpc":"2.0","method":"Broken","id":1}"#).await,
+			-32603
+		);
+	}
+
+	#[tokio::test]
+	async fn DispatchAnswersNoNotification() {
+		let Formality = Formality();
+
+		assert!(
+			Formality
+				.Dispatch(r#"{"jsonrpc":"2.0","method":"Echo","params":["Hello"]}"#)
+				.await
+				.is_none()
+		);
+
+		assert!(Formality.Dispatch(r#"{"jsonrpc":"2.0","method":"Broken"}"#).await.is_none());
+	}
+
+	#[tokio::test]
+	async fn CallAcceptsOptionalArgument() {
+		let Formality = Plan::New()
+			.WithSignature(
+				Signature::New("Read")
+					.WithParameter("Path", Kind::String)
+					.WithOptional("Lossy", Kind::Bool),
+			)
+			.WithFunction("Read", |Argument:Vec<Value>| async move { Ok(json!(Argument.len())) })
+			.unwrap()
+			.Build();
+
+		assert_eq!(Formality.Call("Read", vec![json!("File")]).await.unwrap(), json!(1));
+
+		assert_eq!(
+			Formality.Call("Read", vec![json!("File"), json!(true)]).await.unwrap(),
+			json!(2)
+		);
+
+		for Argument in [
+			vec![],
+			vec![json!("File"), json!("Lossy")],
+			vec![json!("File"), json!(true), json!(true)],
+		] {
+			assert!(matches!(Formality.Call("Read", Argument).await, Err(Error::Argument(_))));
+		}
+	}
+
+	#[tokio::test]
+	async fn RemoveUnregistersFunction() {
+		let Formality = Formality();
+
+		assert!(Formality.Remove("Echo").is_some());
+
+		assert!(!Formality.Contains("Echo"));
+
+		assert!(matches!(
+			Formality.Call("Echo", vec![json!("Hello")]).await,
+			Err(Error::Execution(_))
+		));
+
+		assert!(Formality.Remove("Echo").is_none());
+	}
+
+	#[tokio::test]
+	async fn CallInvokesByName() {
+		let Formality = Formality();
+
+		assert_eq!(Formality.Call("Echo", vec![json!("Hello")]).await.unwrap(), json!("Hello"));
+
+		assert!(matches!(Formality.Call("Missing", vec![]).await, Err(Error::Execution(_))));
+
+		assert!(matches!(Formality.Call("Broken", vec![]).await, Err(Error::Execution(_))));
+	}
+
+	#[tokio::test]
+	async fn CallChecksArguments() {
+		let Formality = Formality();
+
+		for Argument in [vec![], vec![json!("Hello"), json!("World")], vec![json!(1)]] {
+			assert!(matches!(Formality.Call("Echo", Argument).await, Err(Error::Argument(_))));
+		}
+	}
+
+	#[test]
+	fn SignaturesListsEveryAction() {
+		assert_eq!(
+			Formality().Signatures(),
+			vec![
+				Signature::New("Broken"),
+				Signature::New("Echo").WithParameter("Text", Kind::String),
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn CallChecksKindsAndReturn() {
+		let Formality = Plan::New()
+			.WithSignature(
+				Signature::New("Length")
+					.WithParameter("List", Kind::Array)
+					.WithParameter("Extra", Kind::Any)
+					.WithReturn(Kind::Number),
+			)
+			.WithFunction("Length", |Argument:Vec<Value>| async move {
+				Ok(json!(Argument[0].as_array().unwrap().len()))
+			})
+			.unwrap()
+			.WithSignature(Signature::New("Liar").WithReturn(Kind::Number))
+			.WithFunction("Liar", |_| async { Ok(json!("Not a number")) })
+			.unwrap()
+			.Build();
+
+		for Extra in [json!(null), json!("Text"), json!({ "Key": 1 })] {
+			assert_eq!(
+				Formality.Call("Length", vec![json!([1, 2]), Extra]).await.unwrap(),
+				json!(2)
+			);
+		}
+
+		assert!(matches!(
+			Formality.Call("Length", vec![json!({}), json!(null)]).await,
+			Err(Error::Argument(_))
+		));
+
+		assert!(matches!(Formality.Call("Liar", vec![]).await, Err(Error::Execution(_))));
+	}
+
+	#[tokio::test]
+	async fn WithinGivesUpOnSlowCall() {
+		let Formality = Plan::New()
+			.WithSignature(Signature::New("Sleep").WithParameter("Millisecond", Kind::Number))
+			.WithFunction("Sleep", |Argument:Vec<Value>| async move {
+				sleep(Duration::from_millis(Argument[0].as_u64().unwrap())).await;
+
+				Ok(json!("Awake"))
+			})
+			.unwrap()
+			.Build();
+
+		assert!(matches!(
+			Formality.Within("Sleep", vec![json!(500)], Duration::from_millis(50)).await,
+			Err(Error::Timeout(_))
+		));
+
+		assert_eq!(
+			Formality.Within("Sleep", vec![json!(50)], Duration::from_millis(500)).await.unwrap(),
+			json!("Awake")
+		);
+	}
+
+	#[tokio::test]
+	async fn RemovedFunctionMovesToAnotherPlan() {
+		let Function = Formality().Remove("Echo").unwrap();
+
+		let mut Other = Struct::New();
+
+		Other
+			.Sign(Signature::New("Echo").WithParameter("Text", Kind::String))
+			.Add("Echo", Function)
+			.unwrap();
+
+		assert_eq!(Other.Call("Echo", vec![json!("Moved")]).await.unwrap(), json!("Moved"));
+	}
+
+	#[test]
+	fn SchemaDescribesEveryAction() {
+		let Schema = Formality().Schema();
+
+		let Alternative = Schema["oneOf"].as_array().unwrap();
+
+		assert_eq!(Alternative.len(), 2);
+
+		for Alternative in Alternative {
+			assert_eq!(Alternative["required"], json!(["Action", "Argument"]));
+		}
+
+		assert_eq!(Alternative[0]["properties"]["Action"]["const"], json!("Broken"));
+
+		assert_eq!(Alternative[0]["properties"]["Argument"], json!({ "type": "array" }));
+
+		assert_eq!(Alternative[1]["properties"]["Action"]["const"], json!("Echo"));
+
+		assert_eq!(
+			Alternative[1]["properties"]["Argument"],
+			json!({
+				"type": "array",
+				"prefixItems": [Kind::String.Schema()],
+				"minItems": 1,
+				"maxItems": 1,
+			})
+		);
+	}
+
+	#[tokio::test]
+	async fn CallAcceptsVariadicArguments() {
+		let Formality = Plan::New()
+			.WithSignature(Signature::New("Sum").WithVariadic(Kind::Number))
+			.WithFunction("Sum", |Argument:Vec<Value>| async move {
+				Ok(json!(Argument.iter().filter_map(Value::as_f64).sum::<f64>()))
+			})
+			.unwrap()
+			.Build();
+
+		for (Argument, Sum) in [
+			(vec![], 0.0),
+			(vec![json!(1)], 1.0),
+			(vec![json!(1), json!(2), json!(3.5)], 6.5),
+		] {
+			assert_eq!(Formality.Call("Sum", Argument).await.unwrap(), json!(Sum));
+		}
+
+		assert!(matches!(
+			Formality.Call("Sum", vec![json!(1), json!("2")]).await,
+			Err(Error::Argument(_))
+		));
+	}
+
+	#[test]
+	fn ContainsNeedsSignatureAndFunction() {
+		let Formality = Formality();
+
+		Formality.Remove("Broken");
+
+		assert!(Formality.Contains("Echo"));
+
+		assert!(!Formality.Contains("Broken"));
+
+		assert!(!Formality.Contains("Missing"));
+	}
+
+	#[test]
+	fn VerifyDetectsDrift() {
+		let Formality = Formality();
+
+		let mut Expected = Formality.Signatures();
+
+		assert_eq!(Formality.Verify(&Expected), Ok(()));
+
+		Expected.retain(|Signature| Signature.Name == "Echo");
+
+		Expected[0].Return = Some(Kind::String);
+
+		Expected.push(Signature::New("Missing"));
+
+		assert_eq!(
+			Formality.Verify(&Expected),
+			Err(Mismatch::Struct {
+				Missing:vec!["Missing".to_string()],
+				Changed:vec!["Echo".to_string()],
+				Unexpected:vec!["Broken".to_string()],
+			})
+		);
+	}
+}
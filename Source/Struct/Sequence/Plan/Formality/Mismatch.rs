@@ -0,0 +1,38 @@
+/// Describes how the signatures registered in a plan differ from an expected
+/// set, as reported by `Formality::Verify`.
+///
+/// Every list holds action names, sorted.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The expected actions that are not registered.
+	pub Missing:Vec<String>,
+
+	/// The actions registered with another signature than expected.
+	pub Changed:Vec<String>,
+
+	/// The registered actions that were not expected.
+	pub Unexpected:Vec<String>,
+}
+
+impl Struct {
+	/// Returns `true` if the plan matched the expected set.
+	pub fn IsEmpty(&self) -> bool {
+		self.Missing.is_empty() && self.Changed.is_empty() && self.Unexpected.is_empty()
+	}
+}
+
+impl Display for Struct {
+	fn fmt(&self, f:&mut Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"Missing: {:?}, changed: {:?}, unexpected: {:?}",
+			self.Missing, self.Changed, self.Unexpected
+		)
+	}
+}
+
+impl std::error::Error for Struct {}
+
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
@@ -2,12 +2,25 @@
 pub struct Struct {
 	/// A thread-safe, mutable queue of actions.
 	///
-	/// - `Arc` provides shared ownership and thread-safety.
 	/// - `Mutex` ensures exclusive access to the queue.
-	/// - `VecDeque` is used as an efficient double-ended queue.
+	/// - `VecDeque` is used as an efficient double-ended queue, kept sorted
+	///   from highest to lowest priority.
+	/// - `u8` is the priority the action was assigned with.
+	/// - `Instant` is when the action was assigned, used for aging.
 	/// - `Box<dyn Action>` allows for dynamic dispatch of different action
 	///   types.
-	Line:Arc<Mutex<VecDeque<Box<dyn Action>>>>,
+	Line:Mutex<VecDeque<Entry>>,
+
+	/// The maximum number of pending actions accepted by `Offer`,
+	/// `OfferPriority` and `AssignMany`, or `None` for an unbounded queue.
+	Capacity:Option<usize>,
+
+	/// Notified each time an action is added, waking one waiting consumer.
+	Ready:Notify,
+
+	/// How long an action has to wait to gain one level of priority, or `None`
+	/// to keep every action at the priority it was assigned with.
+	Aging:Option<Duration>,
 }
 
 impl Struct {
@@ -15,29 +28,647 @@ impl Struct {
 	///
 	/// # Returns
 	///
-	/// A new `Struct` with an empty action queue.
-	pub fn New() -> Self { Struct { Line:Arc::new(Mutex::new(VecDeque::new())) } }
+	/// A new `Struct` with an empty, unbounded action queue.
+	pub fn New() -> Self {
+		Struct {
+			Line:Mutex::new(VecDeque::new()),
+			Capacity:None,
+			Ready:Notify::new(),
+			Aging:None,
+		}
+	}
+
+	/// Creates a new, empty `Struct` instance that holds at most `Capacity`
+	/// pending actions.
+	///
+	/// The bound is only enforced by the checked methods `Offer`,
+	/// `OfferPriority` and `AssignMany`, which hand the actions back rather
+	/// than go past `Capacity` pending actions. `Assign` and `AssignPriority`
+	/// always enqueue and may grow the queue beyond `Capacity`, so producers
+	/// that need backpressure must use the checked methods.
+	///
+	/// # Arguments
+	///
+	/// * `Capacity` - The maximum number of pending actions.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` with an empty, bounded action queue.
+	pub fn WithCapacity(Capacity:usize) -> Self {
+		Struct {
+			Line:Mutex::new(VecDeque::with_capacity(Capacity)),
+			Capacity:Some(Capacity),
+			Ready:Notify::new(),
+			Aging:None,
+		}
+	}
+
+	/// Raises the priority of waiting actions over time, so that a steady
+	/// stream of higher priority actions cannot starve the others.
+	///
+	/// An action gains one level of priority for every `Aging` it waits, up to
+	/// the highest priority, and `Do` returns the action with the highest
+	/// priority so gained, the longest waiting first among equals. An action
+	/// thus waits at most `255 * Aging`, plus the time to process the actions
+	/// that were assigned before it, before it is returned.
+	///
+	/// # Arguments
+	///
+	/// * `Aging` - The wait that raises the priority of an action by one.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithAging(mut self, Aging:Duration) -> Self {
+		self.Aging = Some(Aging);
+
+		self
+	}
 
 	/// Attempts to retrieve and remove the first action from the queue.
 	///
-	/// This method is asynchronous and will await the lock on the queue.
+	/// Actions with a higher priority are returned first, taking aging into
+	/// account as described in `WithAging`. Actions of equal priority are
+	/// returned in the order they were assigned (first in, first out). This
+	/// method is asynchronous and will await the lock on the queue.
 	///
 	/// # Returns
 	///
 	/// `Option<Box<dyn Action>>` - The first action in the queue if it exists,
 	/// or `None` if the queue is empty.
-	pub async fn Do(&self) -> Option<Box<dyn Action>> { self.Line.lock().await.pop_front() }
+	pub async fn Do(&self) -> Option<Box<dyn Action>> {
+		let mut Line = self.Line.lock().await;
+
+		let Index = self.Next(&Line)?;
+
+		Line.remove(Index).map(|(_, _, Action)| Action)
+	}
+
+	/// Retrieves and removes the first action from the queue, waiting for one
+	/// to be assigned if the queue is empty.
+	///
+	/// This is cancellation safe: if the returned future is dropped, for
+	/// instance by losing a `tokio::select!`, no action has been removed.
+	///
+	/// # Returns
+	///
+	/// `Box<dyn Action>` - The first action in the queue.
+	pub async fn DoWait(&self) -> Box<dyn Action> {
+		loop {
+			// Register interest before checking, so an action assigned in
+			// between still wakes this waiter
+			let Ready = self.Ready.notified();
 
-	/// Adds a new action to the end of the queue.
+			pin!(Ready);
+
+			Ready.as_mut().enable();
+
+			if let Some(Action) = self.Do().await {
+				return Action;
+			}
+
+			Ready.await;
+		}
+	}
+
+	/// Returns a clone of the action that `Do` would return next, leaving the
+	/// queue untouched.
 	///
 	/// This method is asynchronous and will await the lock on the queue.
 	///
+	/// # Returns
+	///
+	/// `Option<Box<dyn Action>>` - A clone of the first action in the queue if
+	/// it exists, or `None` if the queue is empty.
+	pub async fn Peek(&self) -> Option<Box<dyn Action>> {
+		let Line = self.Line.lock().await;
+
+		self.Next(&Line).map(|Index| Line[Index].2.Clone())
+	}
+
+	/// Finds the position of the action that `Do` returns next.
+	///
+	/// Without aging this is the front of the queue, which is kept sorted by
+	/// priority. With aging, every action is ranked by the priority it gained
+	/// while waiting.
+	fn Next(&self, Line:&VecDeque<Entry>) -> Option<usize> {
+		let Some(Aging) = self.Aging else {
+			return if Line.is_empty() { None } else { Some(0) };
+		};
+
+		let Now = Instant::now();
+
+		Line.iter()
+			.enumerate()
+			.max_by_key(|(Index, Entry)| Self::Rank(Entry, *Index, Aging, Now))
+			.map(|(Index, _)| Index)
+	}
+
+	/// Ranks a pending action by the priority it gained through aging by `Now`,
+	/// where the highest rank is returned first. Among equals, the longest
+	/// waiting and then the first queued wins.
+	fn Rank(
+		(Priority, Assigned, _):&Entry,
+		Index:usize,
+		Aging:Duration,
+		Now:Instant,
+	) -> (u128, Reverse<Instant>, Reverse<usize>) {
+		let Gained = Now.duration_since(*Assigned).as_nanos() / Aging.as_nanos().max(1);
+
+		((*Priority as u128 + Gained).min(u8::MAX as u128), Reverse(*Assigned), Reverse(Index))
+	}
+
+	/// Orders every pending action the way successive calls to `Do` would
+	/// return them at this instant.
+	fn Order(Aging:Option<Duration>, Line:impl IntoIterator<Item = Entry>) -> Vec<Box<dyn Action>> {
+		let Some(Aging) = Aging else {
+			return Line.into_iter().map(|(_, _, Action)| Action).collect();
+		};
+
+		let Now = Instant::now();
+
+		let mut Line:Vec<(usize, Entry)> = Line.into_iter().enumerate().collect();
+
+		Line.sort_by_cached_key(|(Index, Entry)| Reverse(Self::Rank(Entry, *Index, Aging, Now)));
+
+		Line.into_iter().map(|(_, (_, _, Action))| Action).collect()
+	}
+
+	/// Adds a new action to the end of the queue with the lowest priority.
+	///
+	/// This ignores the `Capacity` bound; use `Offer` for backpressure. This
+	/// method is asynchronous and will await the lock on the queue.
+	///
 	/// # Arguments
 	///
 	/// * `Action` - The action to be added to the queue.
-	pub async fn Assign(&self, Action:Box<dyn Action>) { self.Line.lock().await.push_back(Action); }
+	pub async fn Assign(&self, Action:Box<dyn Action>) { self.AssignPriority(Action, 0).await; }
+
+	/// Adds several actions to the end of the queue with the lowest priority
+	/// if the queue has room for all of them.
+	///
+	/// The queue is locked once for the whole batch, so the actions stay
+	/// together and in order even while other producers assign concurrently.
+	/// Like `Offer`, a queue created with `WithCapacity` rejects the batch when
+	/// it would hold more than `Capacity` pending actions; the batch is then
+	/// handed back whole and nothing is queued.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The actions to be added to the queue.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if every action was queued, or `Err` giving the actions back
+	/// if the queue has no room for all of them.
+	pub async fn AssignMany(&self, Action:Vec<Box<dyn Action>>) -> Result<(), Vec<Box<dyn Action>>> {
+		let Count = Action.len();
+
+		let mut Line = self.Line.lock().await;
+
+		if self.Capacity.is_some_and(|Capacity| Line.len() + Count > Capacity) {
+			return Err(Action);
+		}
+
+		let Now = Instant::now();
+
+		Line.extend(Action.into_iter().map(|Action| (0, Now, Action)));
+
+		drop(Line);
+
+		for _ in 0..Count {
+			self.Ready.notify_one();
+		}
+
+		Ok(())
+	}
+
+	/// Adds a new action to the queue with the given priority.
+	///
+	/// The action is placed after every pending action with the same or a
+	/// higher priority, and before every pending action with a lower one. This
+	/// ignores the `Capacity` bound; use `OfferPriority` for backpressure.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to be added to the queue.
+	/// * `Priority` - The priority of the action, where higher runs sooner.
+	pub async fn AssignPriority(&self, Action:Box<dyn Action>, Priority:u8) {
+		Self::Insert(&mut *self.Line.lock().await, Action, Priority);
+
+		self.Ready.notify_one();
+	}
+
+	/// Adds a new action to the end of the queue with the lowest priority if
+	/// the queue has room for it.
+	///
+	/// A queue created with `New` always has room. A queue created with
+	/// `WithCapacity` rejects the action while it already holds `Capacity`
+	/// pending actions, letting the caller apply backpressure.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to be added to the queue.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if the action was queued, or `Err` giving the action back if
+	/// the queue is full.
+	pub async fn Offer(&self, Action:Box<dyn Action>) -> Result<(), Box<dyn Action>> {
+		self.OfferPriority(Action, 0).await
+	}
+
+	/// Adds a new action to the queue with the given priority if the queue has
+	/// room for it.
+	///
+	/// The action is placed as by `AssignPriority`, but like `Offer` it is
+	/// rejected while a queue created with `WithCapacity` already holds
+	/// `Capacity` pending actions.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to be added to the queue.
+	/// * `Priority` - The priority of the action, where higher runs sooner.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if the action was queued, or `Err` giving the action back if
+	/// the queue is full.
+	pub async fn OfferPriority(&self, Action:Box<dyn Action>, Priority:u8) -> Result<(), Box<dyn Action>> {
+		let mut Line = self.Line.lock().await;
+
+		if self.Capacity.is_some_and(|Capacity| Line.len() >= Capacity) {
+			return Err(Action);
+		}
+
+		Self::Insert(&mut Line, Action, Priority);
+
+		drop(Line);
+
+		self.Ready.notify_one();
+
+		Ok(())
+	}
+
+	/// Places an action after every pending action with the same or a higher
+	/// priority, keeping the queue sorted.
+	fn Insert(Line:&mut VecDeque<Entry>, Action:Box<dyn Action>, Priority:u8) {
+		let Position = Line.iter().position(|(Rank, ..)| *Rank < Priority).unwrap_or(Line.len());
+
+		Line.insert(Position, (Priority, Instant::now(), Action));
+	}
+
+	/// Removes and returns every action in the queue under a single lock.
+	///
+	/// Producers cannot interleave with the drain, which makes this suitable
+	/// for collecting unfinished work on shutdown.
+	///
+	/// # Returns
+	///
+	/// All pending actions, in the order `Do` would have returned them,
+	/// taking aging into account as of the call.
+	pub async fn Drain(&self) -> Vec<Box<dyn Action>> {
+		Self::Order(self.Aging, self.Line.lock().await.drain(..))
+	}
+
+	/// Consumes the queue and returns every action still pending.
+	///
+	/// The queue is owned at this point, so its actions are moved out without
+	/// locking. A `Struct` shared through an `Arc`, as `Sequence` holds it, has
+	/// to be reclaimed with `Arc::try_unwrap` first, which only succeeds once
+	/// every other owner is dropped; while it is still shared, use `Drain`.
+	///
+	/// # Returns
+	///
+	/// All pending actions, in the order `Do` would have returned them,
+	/// taking aging into account as of the call.
+	pub fn Finish(self) -> Vec<Box<dyn Action>> { Self::Order(self.Aging, self.Line.into_inner()) }
+
+	/// Returns the number of actions waiting in the queue.
+	///
+	/// This method is asynchronous and will await the lock on the queue.
+	pub async fn Len(&self) -> usize { self.Line.lock().await.len() }
+
+	/// Returns `true` if no actions are waiting in the queue.
+	///
+	/// This method is asynchronous and will await the lock on the queue.
+	pub async fn IsEmpty(&self) -> bool { self.Line.lock().await.is_empty() }
+
+	/// Waits until an action is added to the queue.
+	///
+	/// If an action was added while nobody was waiting, this returns
+	/// immediately. The wake-up is only a hint: another consumer may take the
+	/// action first, so callers should `Do` afterwards and handle `None`.
+	pub async fn Wait(&self) { self.Ready.notified().await; }
 }
 
-use std::{collections::VecDeque, sync::Arc};
+/// A pending action with the priority and the instant it was assigned with.
+type Entry = (u8, Instant, Box<dyn Action>);
+
+use std::{
+	cmp::Reverse,
+	collections::VecDeque,
+	time::{Duration, Instant},
+};
+
+use tokio::{pin, sync::Notify};
 
 use crate::{Struct::Sequence::Mutex, Trait::Sequence::Action::Trait as Action};
+
+#[cfg(test)]
+mod Test {
+	use std::time::Duration;
+
+	use tokio::time::{sleep, timeout};
+
+	use super::Struct;
+	use crate::{
+		Struct::Sequence::{Arc, Fixture::Named},
+		Trait::Sequence::Action::Trait as Action,
+	};
+
+	async fn Name(Action:Option<Box<dyn Action>>) -> Option<String> {
+		match Action {
+			Some(Action) => Action.Name().await,
+			None => None,
+		}
+	}
+
+	async fn Names(Action:Vec<Box<dyn Action>>) -> Vec<String> {
+		let mut Names = Vec::new();
+
+		for Action in Action {
+			Names.extend(Action.Name().await);
+		}
+
+		Names
+	}
+
+	#[tokio::test]
+	async fn DoIsFirstInFirstOut() {
+		let Production = Struct::New();
+
+		for Name in ["A", "B", "C"] {
+			Production.Assign(Box::new(Named(Name))).await;
+		}
+
+		let mut Order = Vec::new();
+
+		while let Some(Name) = Name(Production.Do().await).await {
+			Order.push(Name);
+		}
+
+		assert_eq!(Order, ["A", "B", "C"]);
+	}
+
+	#[tokio::test]
+	async fn OfferRejectsAtCapacity() {
+		let Production = Struct::WithCapacity(2);
+
+		assert!(Production.Offer(Box::new(Named("A"))).await.is_ok());
+
+		assert!(Production.Offer(Box::new(Named("B"))).await.is_ok());
+
+		let Rejected = Production.Offer(Box::new(Named("C"))).await.unwrap_err();
+
+		assert_eq!(Rejected.Name().await.as_deref(), Some("C"));
+
+		assert_eq!(Production.Len().await, 2);
+
+		Production.Do().await;
+
+		assert!(Production.Offer(Rejected).await.is_ok());
+	}
+
+	#[tokio::test]
+	async fn OfferPriorityRejectsAtCapacity() {
+		let Production = Struct::WithCapacity(2);
+
+		assert!(Production.OfferPriority(Box::new(Named("Low")), 0).await.is_ok());
+
+		assert!(Production.OfferPriority(Box::new(Named("High")), 9).await.is_ok());
+
+		let Rejected = Production.OfferPriority(Box::new(Named("Top")), u8::MAX).await.unwrap_err();
+
+		assert_eq!(Rejected.Name().await.as_deref(), Some("Top"));
+
+		assert_eq!(Names(Production.Drain().await).await, ["High", "Low"]);
+	}
+
+	#[tokio::test]
+	async fn OfferIsUnboundedByDefault() {
+		let Production = Struct::New();
+
+		for _ in 0..100 {
+			assert!(Production.Offer(Box::new(Named("A"))).await.is_ok());
+		}
+
+		assert_eq!(Production.Len().await, 100);
+	}
+
+	#[tokio::test]
+	async fn LenFollowsAssignAndDo() {
+		let Production = Struct::New();
+
+		assert!(Production.IsEmpty().await);
+
+		Production.Assign(Box::new(Named("A"))).await;
+
+		Production.Assign(Box::new(Named("B"))).await;
+
+		assert_eq!(Production.Len().await, 2);
+
+		Production.Do().await;
+
+		assert_eq!(Production.Len().await, 1);
+
+		assert!(!Production.IsEmpty().await);
+	}
+
+	#[tokio::test]
+	async fn DrainTakesEverything() {
+		let Production = Struct::New();
+
+		for Name in ["A", "B", "C"] {
+			Production.Assign(Box::new(Named(Name))).await;
+		}
+
+		assert_eq!(Names(Production.Drain().await).await, ["A", "B", "C"]);
+
+		assert!(Production.IsEmpty().await);
+	}
+
+	#[tokio::test]
+	async fn DoPrefersHigherPriority() {
+		let Production = Struct::New();
+
+		Production.AssignPriority(Box::new(Named("Low")), 0).await;
+
+		Production.AssignPriority(Box::new(Named("High")), 9).await;
+
+		Production.AssignPriority(Box::new(Named("Middle")), 5).await;
+
+		Production.AssignPriority(Box::new(Named("Second High")), 9).await;
+
+		Production.Assign(Box::new(Named("Second Low"))).await;
+
+		assert_eq!(
+			Names(Production.Drain().await).await,
+			["High", "Second High", "Middle", "Low", "Second Low"]
+		);
+	}
+
+	#[tokio::test]
+	async fn PeekShowsNextDo() {
+		let Production = Struct::New();
+
+		assert!(Production.Peek().await.is_none());
+
+		Production.Assign(Box::new(Named("Low"))).await;
+
+		Production.AssignPriority(Box::new(Named("High")), 5).await;
+
+		for Expected in ["High", "Low"] {
+			assert_eq!(Name(Production.Peek().await).await.as_deref(), Some(Expected));
+
+			assert_eq!(Name(Production.Peek().await).await.as_deref(), Some(Expected));
+
+			assert_eq!(Name(Production.Do().await).await.as_deref(), Some(Expected));
+		}
+
+		assert!(Production.Peek().await.is_none());
+	}
+
+	#[tokio::test]
+	async fn AssignManyQueuesBatchInOrder() {
+		let Production = Struct::WithCapacity(4);
+
+		Production.AssignPriority(Box::new(Named("First")), 3).await;
+
+		assert!(
+			Production
+				.AssignMany(vec![Box::new(Named("A")), Box::new(Named("B")), Box::new(Named("C"))])
+				.await
+				.is_ok()
+		);
+
+		assert_eq!(Production.Len().await, 4);
+
+		assert!(Production.Offer(Box::new(Named("Rejected"))).await.is_err());
+
+		assert_eq!(Names(Production.Drain().await).await, ["First", "A", "B", "C"]);
+	}
+
+	#[tokio::test]
+	async fn AssignManyRejectsBatchPastCapacity() {
+		let Production = Struct::WithCapacity(2);
+
+		Production.Assign(Box::new(Named("First"))).await;
+
+		let Rejected = Production
+			.AssignMany(vec![Box::new(Named("A")), Box::new(Named("B"))])
+			.await
+			.unwrap_err();
+
+		assert_eq!(Names(Rejected).await, ["A", "B"]);
+
+		assert_eq!(Production.Len().await, 1);
+
+		assert!(Production.AssignMany(vec![Box::new(Named("A"))]).await.is_ok());
+
+		assert_eq!(Names(Production.Drain().await).await, ["First", "A"]);
+	}
+
+	#[tokio::test]
+	async fn DoWaitWakesOnLateAssign() {
+		let Production = Arc::new(Struct::New());
+
+		// A wait that loses its race takes nothing off the queue
+		assert!(timeout(Duration::from_millis(10), Production.DoWait()).await.is_err());
+
+		let Waiter = tokio::spawn({
+			let Production = Production.clone();
+
+			async move { Production.DoWait().await.Name().await }
+		});
+
+		sleep(Duration::from_millis(20)).await;
+
+		assert!(!Waiter.is_finished());
+
+		Production.Assign(Box::new(Named("Late"))).await;
+
+		let Name = timeout(Duration::from_secs(5), Waiter).await.expect("DoWait was not woken");
+
+		assert_eq!(Name.unwrap().as_deref(), Some("Late"));
+
+		assert!(Production.IsEmpty().await);
+	}
+
+	#[tokio::test]
+	async fn FinishReturnsLeftovers() {
+		let Production = Arc::new(Struct::New());
+
+		for Name in ["A", "B", "C"] {
+			Production.Assign(Box::new(Named(Name))).await;
+		}
+
+		assert_eq!(Name(Production.Do().await).await.as_deref(), Some("A"));
+
+		let Shared = Production.clone();
+
+		let Production = Arc::try_unwrap(Production).err().expect("queue is still shared");
+
+		drop(Shared);
+
+		let Production = Arc::try_unwrap(Production).ok().expect("queue is no longer shared");
+
+		assert_eq!(Names(Production.Finish()).await, ["B", "C"]);
+	}
+
+	#[tokio::test]
+	async fn DrainFollowsAging() {
+		let Production = Struct::New().WithAging(Duration::from_millis(1));
+
+		Production.Assign(Box::new(Named("Old"))).await;
+
+		sleep(Duration::from_millis(20)).await;
+
+		Production.AssignPriority(Box::new(Named("High")), 5).await;
+
+		Production.AssignPriority(Box::new(Named("Higher")), 9).await;
+
+		assert_eq!(Name(Production.Peek().await).await.as_deref(), Some("Old"));
+
+		assert_eq!(Names(Production.Drain().await).await, ["Old", "Higher", "High"]);
+	}
+
+	#[tokio::test]
+	async fn AgingBoundsWaitUnderFlood() {
+		let Production = Struct::New().WithAging(Duration::from_millis(1));
+
+		let Start = std::time::Instant::now();
+
+		Production.Assign(Box::new(Named("Low"))).await;
+
+		let mut High = 0;
+
+		// A steady flood of top priority actions, one queued for every one taken
+		loop {
+			Production.AssignPriority(Box::new(Named("High")), u8::MAX).await;
+
+			if Name(Production.Do().await).await.as_deref() == Some("Low") {
+				break;
+			}
+
+			High += 1;
+
+			assert!(Start.elapsed() < Duration::from_secs(5), "Low starved");
+		}
+
+		assert!(High > 0);
+
+		// Low reaches the top priority after 255 agings and then wins as older
+		assert!(Start.elapsed() >= Duration::from_millis(255));
+	}
+}
@@ -0,0 +1,138 @@
+/// A site that executes every action it receives directly.
+///
+/// This is the behavior most sequences need: the action runs its own plan
+/// function within the given context, and its result is passed back as is.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Struct;
+
+#[async_trait]
+impl Site for Struct {
+	async fn Receive(&self, Action:Box<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::Life::Struct as Life,
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
+
+pub mod Fault;
+pub mod Layer;
+pub mod Route;
+
+#[cfg(test)]
+mod Test {
+	use dashmap::DashMap;
+	use serde_json::{Value, json};
+
+	use super::Struct;
+	use crate::{
+		Enum::Sequence::Action::{Error::Enum as Error, Signature::Kind::Enum as Kind},
+		Struct::Sequence::{
+			Action::{Signature::Struct as Signature, Struct as Action},
+			Arc,
+			Fixture::Context,
+			Plan::Struct as Plan,
+		},
+		Trait::Sequence::Site::Trait as Site,
+	};
+
+	#[tokio::test]
+	async fn ReceiveWritesThenReads() {
+		let Store = Arc::new(DashMap::<String, Value>::new());
+
+		let (Written, Read) = (Store.clone(), Store.clone());
+
+		let Plan = Arc::new(
+			Plan::New()
+				.WithSignature(
+					Signature::New("Write")
+						.WithParameter("Path", Kind::String)
+						.WithParameter("Content", Kind::String),
+				)
+				.WithFunction("Write", move |Argument:Vec<Value>| {
+					let Path = Argument[0].as_str().unwrap_or_default().to_string();
+
+					Written.insert(Path, Argument[1].clone());
+
+					async { Ok(Value::Null) }
+				})
+				.unwrap()
+				.WithSignature(Signature::New("Read").WithParameter("Path", Kind::String))
+				.WithFunction("Read", move |Argument:Vec<Value>| {
+					let Path = Argument[0].as_str().unwrap_or_default().to_string();
+
+					let Content = Read.get(&Path).map(|Entry| Entry.clone());
+
+					async move { Content.ok_or(Error::NotFound(Path)) }
+				})
+				.unwrap()
+				.Build(),
+		);
+
+		let Context = Context();
+
+		assert!(
+			Struct
+				.Receive(Box::new(Action::New("Read", json!("File"), Plan.clone())), &Context)
+				.await
+				.is_err()
+		);
+
+		Struct
+			.Receive(
+				Box::new(Action::New("Write", json!(["File", "Text"]), Plan.clone())),
+				&Context,
+			)
+			.await
+			.unwrap();
+
+		Struct
+			.Receive(Box::new(Action::New("Read", json!("File"), Plan.clone())), &Context)
+			.await
+			.unwrap();
+
+		assert_eq!(*Store.get("File").unwrap(), json!("Text"));
+	}
+
+	#[tokio::test]
+	async fn ReceiveRunsCustomOperation() {
+		let Seen = Arc::new(DashMap::<String, Value>::new());
+
+		let Resized = Seen.clone();
+
+		let Plan = Arc::new(
+			Plan::New()
+				.WithSignature(Signature::New("Resize").WithParameter("Payload", Kind::Object))
+				.WithFunction("Resize", move |Argument:Vec<Value>| {
+					Resized.insert("Resize".to_string(), Argument[0].clone());
+
+					async { Ok(Value::Null) }
+				})
+				.unwrap()
+				.Build(),
+		);
+
+		let Context = Context();
+
+		let Payload = json!({ "Image": "Logo.png", "Width": 64 });
+
+		Struct
+			.Receive(Box::new(Action::New("Resize", Payload.clone(), Plan.clone())), &Context)
+			.await
+			.unwrap();
+
+		assert_eq!(*Seen.get("Resize").unwrap(), Payload);
+
+		assert!(
+			Struct
+				.Receive(Box::new(Action::New("Resize", json!("Logo.png"), Plan)), &Context)
+				.await
+				.is_err()
+		);
+	}
+}
@@ -0,0 +1,250 @@
+/// A site that delays and fails actions on purpose before handing them to
+/// another site.
+///
+/// This is meant for testing how a sequence copes with slow and failing
+/// sites. Faults follow a fixed pattern: the first calls fail, then calls fail
+/// at random with a given chance, drawn from a seeded generator so that the
+/// same seed reproduces the same failures for calls made one after another.
+/// A failed call never reaches the inner site.
+pub struct Struct {
+	/// The site receiving every action that is not failed.
+	pub Inner:Arc<dyn Site>,
+
+	/// The time every call waits before it succeeds or fails.
+	Delay:Duration,
+
+	/// The number of calls to fail before any call is let through.
+	First:usize,
+
+	/// The chance, from `0.0` to `1.0`, that a later call fails, kept within
+	/// that range by `WithChance`.
+	Chance:f64,
+
+	/// The generator deciding which later calls fail.
+	Random:Mutex<StdRng>,
+
+	/// The number of calls received so far.
+	Count:AtomicUsize,
+}
+
+impl Struct {
+	/// Creates a new `Struct` instance that injects no faults.
+	///
+	/// # Arguments
+	///
+	/// * `Inner` - The site to wrap, such as `Site::Struct`.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance that forwards every action to `Inner`.
+	pub fn New(Inner:Arc<dyn Site>) -> Self {
+		Struct {
+			Inner,
+			Delay:Duration::ZERO,
+			First:0,
+			Chance:0.0,
+			Random:Mutex::new(StdRng::seed_from_u64(0)),
+			Count:AtomicUsize::new(0),
+		}
+	}
+
+	/// Delays every call by `Delay`.
+	///
+	/// # Arguments
+	///
+	/// * `Delay` - The time every call waits.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithDelay(mut self, Delay:Duration) -> Self {
+		self.Delay = Delay;
+
+		self
+	}
+
+	/// Fails the first `First` calls.
+	///
+	/// # Arguments
+	///
+	/// * `First` - The number of calls to fail.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithFirst(mut self, First:usize) -> Self {
+		self.First = First;
+
+		self
+	}
+
+	/// Fails every later call with the given chance.
+	///
+	/// A chance outside `0.0` to `1.0` is clamped to that range, and `NaN` is
+	/// read as `0.0`.
+	///
+	/// # Arguments
+	///
+	/// * `Chance` - The chance that a call fails, from `0.0` to `1.0`.
+	/// * `Seed` - The seed of the generator deciding which calls fail.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithChance(mut self, Chance:f64, Seed:u64) -> Self {
+		self.Chance = if Chance.is_nan() { 0.0 } else { Chance.clamp(0.0, 1.0) };
+
+		self.Random = Mutex::new(StdRng::seed_from_u64(Seed));
+
+		self
+	}
+
+	/// Returns the number of calls received so far, failed ones included.
+	pub fn Count(&self) -> usize { self.Count.load(Ordering::Relaxed) }
+}
+
+#[async_trait]
+impl Site for Struct {
+	async fn Receive(&self, Action:Box<dyn Action>, Context:&Life) -> Result<(), Error> {
+		let Call = self.Count.fetch_add(1, Ordering::Relaxed);
+
+		if !self.Delay.is_zero() {
+			sleep(self.Delay).await;
+		}
+
+		let Fail = Call < self.First || self.Random.lock().await.random_bool(self.Chance);
+
+		if Fail {
+			return Err(Error::Execution(format!("Injected fault on call {}", Call + 1)));
+		}
+
+		self.Inner.Receive(Action, Context).await
+	}
+
+	async fn Healthy(&self) -> bool { self.Inner.Healthy().await }
+}
+
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicUsize, Ordering},
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use tokio::time::sleep;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{Life::Struct as Life, Mutex},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
+
+#[cfg(test)]
+mod Test {
+	use std::{
+		sync::atomic::{AtomicUsize, Ordering},
+		time::{Duration, Instant},
+	};
+
+	use async_trait::async_trait;
+
+	use super::{Arc, Struct};
+	use crate::{
+		Enum::Sequence::Action::Error::Enum as Error,
+		Struct::Sequence::{
+			Fixture::{Context, Probe},
+			Life::Struct as Life,
+			Site::Struct as Direct,
+		},
+		Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+	};
+
+	/// A site counting the actions that reach it.
+	#[derive(Default)]
+	struct Reached(AtomicUsize);
+
+	#[async_trait]
+	impl Site for Reached {
+		async fn Receive(&self, _Action:Box<dyn Action>, _Context:&Life) -> Result<(), Error> {
+			self.0.fetch_add(1, Ordering::Relaxed);
+
+			Ok(())
+		}
+	}
+
+	#[tokio::test]
+	async fn ChanceAboveOneFailsEveryCall() {
+		let Fault = Struct::New(Arc::new(Direct)).WithChance(2.0, 0);
+
+		for _ in 0..10 {
+			assert!(Fault.Receive(Box::new(Probe), &Context()).await.is_err());
+		}
+	}
+
+	#[tokio::test]
+	async fn ChanceNaNFailsNoCall() {
+		let Fault = Struct::New(Arc::new(Direct)).WithChance(f64::NAN, 0);
+
+		for _ in 0..10 {
+			assert!(Fault.Receive(Box::new(Probe), &Context()).await.is_ok());
+		}
+	}
+
+	#[tokio::test]
+	async fn FirstFailsBeforeReachingInner() {
+		let Inner = Arc::new(Reached::default());
+
+		let Fault = Struct::New(Inner.clone()).WithFirst(3);
+
+		for _ in 0..3 {
+			assert!(Fault.Receive(Box::new(Probe), &Context()).await.is_err());
+		}
+
+		assert_eq!(Inner.0.load(Ordering::Relaxed), 0);
+
+		for _ in 0..2 {
+			assert!(Fault.Receive(Box::new(Probe), &Context()).await.is_ok());
+		}
+
+		assert_eq!(Inner.0.load(Ordering::Relaxed), 2);
+
+		assert_eq!(Fault.Count(), 5);
+	}
+
+	#[tokio::test]
+	async fn SameSeedRepeatsFailures() {
+		let mut Pattern = Vec::new();
+
+		for _ in 0..2 {
+			let Fault = Struct::New(Arc::new(Direct)).WithChance(0.5, 42);
+
+			let mut Passed = Vec::new();
+
+			for _ in 0..100 {
+				Passed.push(Fault.Receive(Box::new(Probe), &Context()).await.is_ok());
+			}
+
+			Pattern.push(Passed);
+		}
+
+		assert_eq!(Pattern[0], Pattern[1]);
+
+		assert!(Pattern[0].contains(&true) && Pattern[0].contains(&false));
+	}
+
+	#[tokio::test]
+	async fn DelayHoldsEveryCall() {
+		let Fault = Struct::New(Arc::new(Direct)).WithDelay(Duration::from_millis(20));
+
+		for _ in 0..3 {
+			let Start = Instant::now();
+
+			assert!(Fault.Receive(Box::new(Probe), &Context()).await.is_ok());
+
+			assert!(Start.elapsed() >= Duration::from_millis(20));
+		}
+	}
+}
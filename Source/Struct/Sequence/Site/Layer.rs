@@ -0,0 +1,190 @@
+/// A site that wraps another site in a stack of layers.
+///
+/// Every action passes through the `Before` hook of each layer, in the order
+/// the layers were added, then reaches the inner site. The `After` hooks then
+/// run in reverse order, so the first layer added is the outermost one.
+///
+/// If a `Before` hook rejects the action, the `After` hooks of the layers
+/// that already let it through still run, in reverse order, with that error,
+/// so every layer that saw `Before` succeed also sees `After`.
+#[derive(Clone)]
+pub struct Struct {
+	/// The site that processes actions once every layer let them through.
+	pub Inner:Arc<dyn Site>,
+
+	/// The layers wrapped around the inner site, outermost first.
+	pub Layer:Vec<Arc<dyn Layer>>,
+}
+
+impl Struct {
+	/// Creates a new `Struct` instance without any layers.
+	///
+	/// # Arguments
+	///
+	/// * `Inner` - The site to wrap.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance that forwards every action to `Inner`.
+	pub fn New(Inner:Arc<dyn Site>) -> Self { Struct { Inner, Layer:Vec::new() } }
+
+	/// Adds a layer inside all the layers added so far.
+	///
+	/// # Arguments
+	///
+	/// * `Layer` - The layer to add.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithLayer(mut self, Layer:Arc<dyn Layer>) -> Self {
+		self.Layer.push(Layer);
+
+		self
+	}
+}
+
+#[async_trait]
+impl Site for Struct {
+	async fn Receive(&self, Action:Box<dyn Action>, Context:&Life) -> Result<(), Error> {
+		let mut Passed = 0;
+
+		let mut Result = Ok(());
+
+		for Layer in &self.Layer {
+			Result = Layer.Before(Action.as_ref(), Context).await;
+
+			if Result.is_err() {
+				break;
+			}
+
+			Passed += 1;
+		}
+
+		if Result.is_ok() {
+			Result = self.Inner.Receive(Action, Context).await;
+		}
+
+		for Layer in self.Layer[..Passed].iter().rev() {
+			Layer.After(&Result, Context).await;
+		}
+
+		Result
+	}
+
+	async fn Healthy(&self) -> bool { self.Inner.Healthy().await }
+}
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::Life::Struct as Life,
+	Trait::Sequence::{Action::Trait as Action, Layer::Trait as Layer, Site::Trait as Site},
+};
+
+#[cfg(test)]
+mod Test {
+	use async_trait::async_trait;
+
+	use super::{Arc, Struct};
+	use crate::{
+		Enum::Sequence::Action::Error::Enum as Error,
+		Struct::Sequence::{
+			Fixture::{Context, Named, Probe},
+			Life::Struct as Life,
+			Site,
+		},
+		Trait::Sequence::{Action::Trait as Action, Layer::Trait as Layer, Site::Trait as _},
+	};
+
+	/// A layer recording its hooks in a shared log, rejecting in `Before` if
+	/// asked to.
+	struct Record {
+		Name:&'static str,
+
+		Reject:bool,
+
+		Log:Arc<std::sync::Mutex<Vec<String>>>,
+	}
+
+	#[async_trait]
+	impl Layer for Record {
+		async fn Before(&self, _Action:&dyn Action, _Context:&Life) -> Result<(), Error> {
+			self.Log.lock().unwrap().push(format!("Before {}", self.Name));
+
+			if self.Reject {
+				return Err(Error::Execution(format!("Rejected by {}", self.Name)));
+			}
+
+			Ok(())
+		}
+
+		async fn After(&self, Result:&Result<(), Error>, _Context:&Life) {
+			self.Log.lock().unwrap().push(format!("After {} {}", self.Name, Result.is_ok()));
+		}
+	}
+
+	/// A layer logging the name of every action it lets through.
+	struct Journal(Arc<std::sync::Mutex<Vec<String>>>);
+
+	#[async_trait]
+	impl Layer for Journal {
+		async fn Before(&self, Action:&dyn Action, _Context:&Life) -> Result<(), Error> {
+			let Name = Action.Name().await.unwrap_or_default();
+
+			self.0.lock().unwrap().push(Name);
+
+			Ok(())
+		}
+	}
+
+	#[tokio::test]
+	async fn JournalRecordsEachAction() {
+		let Log = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+		let Stack = Struct::New(Arc::new(Site::Struct)).WithLayer(Arc::new(Journal(Log.clone())));
+
+		for Name in ["Read", "Write", "Read"] {
+			Stack.Receive(Box::new(Named(Name)), &Context()).await.unwrap();
+		}
+
+		assert_eq!(*Log.lock().unwrap(), ["Read", "Write", "Read"]);
+	}
+
+	#[tokio::test]
+	async fn RejectRunsAfterOfPassedLayers() {
+		let Log = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+		let Layer = |Name, Reject| Arc::new(Record { Name, Reject, Log:Log.clone() });
+
+		let Stack = Struct::New(Arc::new(Site::Struct))
+			.WithLayer(Layer("A", false))
+			.WithLayer(Layer("B", false))
+			.WithLayer(Layer("C", true))
+			.WithLayer(Layer("D", false));
+
+		assert!(Stack.Receive(Box::new(Probe), &Context()).await.is_err());
+
+		assert_eq!(
+			*Log.lock().unwrap(),
+			["Before A", "Before B", "Before C", "After B false", "After A false"]
+		);
+	}
+
+	#[tokio::test]
+	async fn LayersWrapInnerSite() {
+		let Log = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+		let Layer = |Name| Arc::new(Record { Name, Reject:false, Log:Log.clone() });
+
+		let Stack =
+			Struct::New(Arc::new(Site::Struct)).WithLayer(Layer("A")).WithLayer(Layer("B"));
+
+		Stack.Receive(Box::new(Probe), &Context()).await.unwrap();
+
+		assert_eq!(*Log.lock().unwrap(), ["Before A", "Before B", "After B true", "After A true"]);
+	}
+}
@@ -0,0 +1,130 @@
+/// A site that hands each action to another site chosen by its name.
+///
+/// Actions are matched against the routes by `Action::Name`. Actions without
+/// a name, or whose name has no route, go to the fallback site, so existing
+/// sites can be composed without matching on names in one of them.
+#[derive(Clone)]
+pub struct Struct {
+	/// The sites receiving actions, keyed by action name.
+	pub Route:HashMap<String, Arc<dyn Site>>,
+
+	/// The site receiving every action without a route.
+	pub Fallback:Arc<dyn Site>,
+}
+
+impl Struct {
+	/// Creates a new `Struct` instance without any routes.
+	///
+	/// # Arguments
+	///
+	/// * `Fallback` - The site receiving actions without a route.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance that forwards every action to `Fallback`.
+	pub fn New(Fallback:Arc<dyn Site>) -> Self { Struct { Route:HashMap::new(), Fallback } }
+
+	/// Routes the actions named `Name` to `Site`, replacing any earlier route
+	/// for that name.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the actions to route.
+	/// * `Site` - The site receiving them.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRoute(mut self, Name:&str, Site:Arc<dyn Site>) -> Self {
+		self.Route.insert(Name.to_string(), Site);
+
+		self
+	}
+}
+
+#[async_trait]
+impl Site for Struct {
+	async fn Receive(&self, Action:Box<dyn Action>, Context:&Life) -> Result<(), Error> {
+		let Site = match Action.Name().await {
+			Some(Name) => self.Route.get(&Name).unwrap_or(&self.Fallback),
+			None => &self.Fallback,
+		};
+
+		Site.Receive(Action, Context).await
+	}
+
+	async fn Healthy(&self) -> bool {
+		// Any site may receive the next action, so every one has to be healthy
+		for Site in self.Route.values() {
+			if !Site.Healthy().await {
+				return false;
+			}
+		}
+
+		self.Fallback.Healthy().await
+	}
+}
+
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::Life::Struct as Life,
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
+
+#[cfg(test)]
+mod Test {
+	use async_trait::async_trait;
+
+	use super::{Arc, Struct};
+	use crate::{
+		Enum::Sequence::Action::Error::Enum as Error,
+		Struct::Sequence::{
+			Fixture::{Context, Named, Probe},
+			Life::Struct as Life,
+		},
+		Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+	};
+
+	/// A site recording which site received which action in a shared log.
+	struct Recorder(&'static str, Arc<std::sync::Mutex<Vec<String>>>);
+
+	#[async_trait]
+	impl Site for Recorder {
+		async fn Receive(&self, Action:Box<dyn Action>, _Context:&Life) -> Result<(), Error> {
+			let Name = Action.Name().await.unwrap_or_else(|| "unnamed".to_string());
+
+			self.1.lock().unwrap().push(format!("{} {}", self.0, Name));
+
+			Ok(())
+		}
+	}
+
+	#[tokio::test]
+	async fn RoutesByName() {
+		let Log = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+		let Site = |Label| Arc::new(Recorder(Label, Log.clone()));
+
+		let Route = Struct::New(Site("Fallback"))
+			.WithRoute("Read", Site("Disk"))
+			.WithRoute("Write", Site("Disk"))
+			.WithRoute("Checksum", Site("Hash"));
+
+		let Context = Context();
+
+		for Name in ["Read", "Checksum", "Write", "Other"] {
+			Route.Receive(Box::new(Named(Name)), &Context).await.unwrap();
+		}
+
+		Route.Receive(Box::new(Probe), &Context).await.unwrap();
+
+		assert_eq!(
+			*Log.lock().unwrap(),
+			["Disk Read", "Hash Checksum", "Disk Write", "Fallback Other", "Fallback unnamed"]
+		);
+	}
+}
@@ -0,0 +1,38 @@
+/// Represents the outcome of a `Run` loop once it stops.
+///
+/// Every action the loop took off a line is counted once, however often it
+/// was retried.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The number of actions processed.
+	pub Processed:u64,
+
+	/// The number of actions that succeeded, retries included.
+	pub Succeeded:u64,
+
+	/// The number of actions that failed for good.
+	pub Failed:u64,
+}
+
+impl Struct {
+	/// Adds up the summaries of several loops, such as those of a pool.
+	///
+	/// # Arguments
+	///
+	/// * `Summary` - The summaries to add up.
+	///
+	/// # Returns
+	///
+	/// A `Struct` holding the sum of every count.
+	pub fn Total(Summary:impl IntoIterator<Item = Struct>) -> Self {
+		Summary.into_iter().fold(Struct::default(), |Total, Summary| {
+			Struct {
+				Processed:Total.Processed + Summary.Processed,
+				Succeeded:Total.Succeeded + Summary.Succeeded,
+				Failed:Total.Failed + Summary.Failed,
+			}
+		})
+	}
+}
+
+use serde::{Deserialize, Serialize};
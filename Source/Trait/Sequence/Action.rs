@@ -27,6 +27,26 @@ pub trait Trait: Send + Sync {
 	///
 	/// Returns a `Box<dyn Trait>` containing a clone of the action.
 	fn Clone(&self) -> Box<dyn Trait>;
+
+	/// Returns the name of the action, if it has one.
+	///
+	/// Sites use the name to tell actions apart, for instance to route them.
+	///
+	/// # Returns
+	///
+	/// Returns the name of the action, or `None` by default.
+	async fn Name(&self) -> Option<String> { None }
+
+	/// Returns the estimated cost of executing the action.
+	///
+	/// A sequence created with `WithLimit` lets actions into its site only
+	/// while their total cost stays within the limit, so expensive actions
+	/// should report a higher cost than cheap ones.
+	///
+	/// # Returns
+	///
+	/// Returns the cost of the action, `1` by default.
+	async fn Cost(&self) -> u32 { 1 }
 }
 
 /// Implementation of the `Trait` for
@@ -35,18 +55,36 @@ pub trait Trait: Send + Sync {
 /// This implementation allows any `Struct<T>` that satisfies the bounds
 /// to be used as a `Trait` object.
 #[async_trait]
-impl<T:Send + Sync + Clone + 'static> Trait for crate::Struct::Sequence::Action::Struct<T> {
+impl<T:Send + Sync + Clone + Serialize + for<'de> Deserialize<'de> + 'static> Trait
+	for crate::Struct::Sequence::Action::Struct<T>
+{
 	async fn Execute(&self, Context:&Life) -> Result<(), Error> {
-		// Delegates to the struct's own `Execute` method
-		self.Execute(Context).await
+		// Delegates to the struct's own `Execute` method, named explicitly so
+		// that it cannot resolve to this one
+		crate::Struct::Sequence::Action::Struct::<T>::Execute(self, Context).await
 	}
 
 	fn Clone(&self) -> Box<dyn Trait> {
 		// Creates a new boxed trait object containing a clone of self
 		Box::new(self.clone())
 	}
+
+	async fn Name(&self) -> Option<String> {
+		// Reads the name the action was created with
+		self.Metadata.Get("Action").await.and_then(|Action| Action.as_str().map(str::to_string))
+	}
+
+	async fn Cost(&self) -> u32 {
+		// Reads the cost from the metadata, falling back to the default
+		self.Metadata
+			.Get("Cost")
+			.await
+			.and_then(|Cost| Cost.as_u64())
+			.map_or(1, |Cost| Cost.min(u32::MAX as u64) as u32)
+	}
 }
 
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 use crate::{Enum::Sequence::Action::Error::Enum as Error, Struct::Sequence::Life::Struct as Life};
@@ -0,0 +1,40 @@
+/// Trait for cross-cutting behavior wrapped around a site.
+///
+/// Layers are composed by `crate::Struct::Sequence::Site::Layer::Struct`,
+/// which calls `Before` on every layer, hands the action to the inner site, and
+/// then calls `After` on every layer in reverse order. Both hooks do nothing
+/// by default, so a layer only implements the one it needs.
+#[async_trait]
+pub trait Trait: Send + Sync {
+	/// Runs before the action reaches the inner site.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action about to be processed.
+	/// * `Context` - A reference to the `Life` context in which the action is
+	///   executed.
+	///
+	/// # Returns
+	///
+	/// Returns `Ok(())` to let the action through, or an `Error` to reject it
+	/// without reaching the inner site or any later layer. The earlier layers
+	/// then see the error in `After`.
+	async fn Before(&self, _Action:&dyn Action, _Context:&Life) -> Result<(), Error> { Ok(()) }
+
+	/// Runs after the inner site processed the action.
+	///
+	/// # Arguments
+	///
+	/// * `Result` - The result of processing the action.
+	/// * `Context` - A reference to the `Life` context in which the action was
+	///   executed.
+	async fn After(&self, _Result:&Result<(), Error>, _Context:&Life) {}
+}
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::Life::Struct as Life,
+	Trait::Sequence::Action::Trait as Action,
+};
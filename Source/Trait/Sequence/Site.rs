@@ -3,6 +3,11 @@
 /// Types that implement this trait must be able to handle actions
 /// asynchronously. The trait is marked with `Send` and `Sync` to ensure thread
 /// safety.
+///
+/// State that belongs to the site itself, such as connection pools, lives in
+/// the implementing type. State that belongs to a run, such as configuration
+/// in `Fate` or values in `Cache`, comes in through the `Life` context, so the
+/// same site can serve sequences with different contexts.
 #[async_trait::async_trait]
 pub trait Trait: Send + Sync {
 	/// Processes a given action asynchronously and returns the result.
@@ -32,4 +37,111 @@ pub trait Trait: Send + Sync {
 		Action:Box<dyn super::Action::Trait>,
 		Context:&crate::Struct::Sequence::Life::Struct,
 	) -> Result<(), crate::Enum::Sequence::Action::Error::Enum>;
+
+	/// Processes several actions at once and returns one result per action.
+	///
+	/// Sites backed by a remote service can override this to submit the whole
+	/// batch in a single round trip. The default implementation calls
+	/// `Receive` for each action in turn.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The actions to be processed.
+	/// * `Context` - A reference to the `Life` context in which the actions are
+	///   executed.
+	///
+	/// # Returns
+	///
+	/// The result of each action, in the same order as `Action`.
+	async fn ReceiveBatch(
+		&self,
+		Action:Vec<Box<dyn super::Action::Trait>>,
+		Context:&crate::Struct::Sequence::Life::Struct,
+	) -> Vec<Result<(), crate::Enum::Sequence::Action::Error::Enum>> {
+		let mut Result = Vec::with_capacity(Action.len());
+
+		for Action in Action {
+			Result.push(self.Receive(Action, Context).await);
+		}
+
+		Result
+	}
+
+	/// Reports whether the site is ready to receive actions.
+	///
+	/// Sites backed by something that can become unreachable, such as a
+	/// network mount, can override this. A `Run` loop takes no action while
+	/// its site reports unhealthy, leaving the actions queued for other loops,
+	/// and asks again after its poll interval.
+	///
+	/// # Returns
+	///
+	/// `true` if the site can receive actions, which is the default.
+	async fn Healthy(&self) -> bool { true }
+}
+
+#[cfg(test)]
+mod Test {
+	use async_trait::async_trait;
+
+	use super::Trait as Site;
+	use crate::{
+		Enum::Sequence::Action::Error::Enum as Error,
+		Struct::Sequence::{Arc, Fixture::Context, Life::Struct as Life},
+		Trait::Sequence::Action::Trait as Action,
+	};
+
+	/// An action that records its index as it runs and fails if it is odd.
+	struct Mark(usize, Arc<std::sync::Mutex<Vec<usize>>>);
+
+	#[async_trait]
+	impl Action for Mark {
+		async fn Execute(&self, _Context:&Life) -> Result<(), Error> {
+			self.1.lock().unwrap().push(self.0);
+
+			if self.0 % 2 == 1 {
+				return Err(Error::Execution(self.0.to_string()));
+			}
+
+			Ok(())
+		}
+
+		fn Clone(&self) -> Box<dyn Action> { Box::new(Mark(self.0, self.1.clone())) }
+	}
+
+	/// A site that only implements `Receive`, keeping the default batch.
+	struct Direct;
+
+	#[async_trait]
+	impl Site for Direct {
+		async fn Receive(&self, Action:Box<dyn Action>, Context:&Life) -> Result<(), Error> {
+			Action.Execute(Context).await
+		}
+	}
+
+	#[tokio::test]
+	async fn ReceiveBatchKeepsOrder() {
+		let Context = Context();
+
+		let Log = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+		let Result = Direct
+			.ReceiveBatch(
+				(0..5).map(|Index| Box::new(Mark(Index, Log.clone())) as Box<dyn Action>).collect(),
+				&Context,
+			)
+			.await;
+
+		assert_eq!(*Log.lock().unwrap(), [0, 1, 2, 3, 4]);
+
+		assert_eq!(Result.len(), 5);
+
+		for (Index, Result) in Result.into_iter().enumerate() {
+			match Result {
+				Ok(()) => assert_eq!(Index % 2, 0),
+				Err(Error::Execution(Failed)) => assert_eq!(Failed, Index.to_string()),
+				Err(_Error) => panic!("unexpected error: {}", _Error),
+			}
+		}
+	}
 }
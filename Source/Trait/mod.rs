@@ -2,5 +2,7 @@ pub mod Sequence {
 
 	pub mod Action;
 
+	pub mod Layer;
+
 	pub mod Site;
 }
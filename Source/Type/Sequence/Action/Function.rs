@@ -0,0 +1,21 @@
+/// Represents a boxed plan function, as registered on a `Formality`.
+///
+/// This type alias defines a function that:
+/// - Takes the arguments of a call as a vector of JSON values
+/// - Returns a pinned, boxed future resolving to a `Result` where:
+///   - The success case is the JSON value the function returns
+///   - The error case is `crate::Enum::Sequence::Action::Error::Enum`
+/// - Implements `Send` and `Sync` traits, making it safe to send between
+///   threads and use from multiple threads
+///
+/// It's the form every function takes once added to a plan, whatever its
+/// original type.
+pub type Type = Box<
+	dyn Fn(Vec<Value>) -> Pin<Box<dyn Future<Output = Result<Value, Error>> + Send>> + Send + Sync,
+>;
+
+use std::{future::Future, pin::Pin};
+
+use serde_json::Value;
+
+use crate::Enum::Sequence::Action::Error::Enum as Error;
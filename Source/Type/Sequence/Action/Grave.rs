@@ -0,0 +1,13 @@
+/// Represents the sending half of a dead-letter channel.
+///
+/// This type alias defines an unbounded sender of pairs made of:
+/// - The action that failed for good, as a boxed
+///   `crate::Trait::Sequence::Action::Trait`
+/// - The final `crate::Enum::Sequence::Action::Error::Enum` it failed with
+///
+/// It's used by a sequence to hand over actions it gave up on, so they can be
+/// inspected, stored or retried elsewhere.
+pub type Type = tokio::sync::mpsc::UnboundedSender<(
+	Box<dyn crate::Trait::Sequence::Action::Trait>,
+	crate::Enum::Sequence::Action::Error::Enum,
+)>;
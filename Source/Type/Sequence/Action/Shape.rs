@@ -0,0 +1,20 @@
+/// Represents a thread-safe, reference-counted function that rewrites an
+/// action before it is processed.
+///
+/// This type alias defines a function that:
+/// - Takes a boxed `crate::Trait::Sequence::Action::Trait` by value
+/// - Returns the boxed action to process in its place, which may be the same
+///   action, a modified clone or a different action altogether
+/// - Is wrapped in an `Arc` (Atomic Reference Counted) for thread-safe sharing
+/// - Implements `Send` and `Sync` traits, making it safe to send between
+///   threads and use from multiple threads
+///
+/// It's used by a sequence to centralize rewriting, such as adjusting
+/// metadata, instead of every site doing it.
+pub type Type = crate::Struct::Sequence::Arc<
+	dyn Fn(
+			Box<dyn crate::Trait::Sequence::Action::Trait>,
+		) -> Box<dyn crate::Trait::Sequence::Action::Trait>
+		+ Send
+		+ Sync,
+>;
@@ -1,5 +1,11 @@
 pub mod Sequence {
 	pub mod Action {
 		pub mod Cycle;
+
+		pub mod Function;
+
+		pub mod Grave;
+
+		pub mod Shape;
 	}
 }